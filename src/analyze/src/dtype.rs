@@ -0,0 +1,36 @@
+use polars::prelude::{DataType, UnknownKind};
+use serde::{Deserialize, Deserializer, Serializer};
+
+// polars' own serde representation of `DataType` changes between versions,
+// so we store the short name polars displays (`f64`, `str`, ...) instead.
+pub fn serialize<S: Serializer>(dtype: &DataType, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&dtype.to_string())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DataType, D::Error> {
+	let s = String::deserialize(deserializer)?;
+
+	Ok(parse(&s))
+}
+
+pub fn parse(s: &str) -> DataType {
+	match s {
+		"null" => DataType::Null,
+		"bool" => DataType::Boolean,
+		"u8" => DataType::UInt8,
+		"u16" => DataType::UInt16,
+		"u32" => DataType::UInt32,
+		"u64" => DataType::UInt64,
+		"i8" => DataType::Int8,
+		"i16" => DataType::Int16,
+		"i32" => DataType::Int32,
+		"i64" => DataType::Int64,
+		"f32" => DataType::Float32,
+		"f64" => DataType::Float64,
+		"str" => DataType::String,
+		"binary" => DataType::Binary,
+		"date" => DataType::Date,
+		"time" => DataType::Time,
+		_ => DataType::Unknown(UnknownKind::Any),
+	}
+}
@@ -0,0 +1,326 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use polars::prelude::{PolarsResult, Series};
+use serde::{Deserialize, Serialize};
+
+use float::Float;
+
+use crate::{values, Analysis};
+
+/// block characters of a sparkline, from empty to full
+const LEVELS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// rule used to choose the number of bins of a histogram
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BinRule {
+	#[default]
+	Sturges,
+	Scott,
+	FreedmanDiaconis,
+	Fixed(usize),
+}
+
+impl FromStr for BinRule {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"sturges" => Ok(BinRule::Sturges),
+			"scott" => Ok(BinRule::Scott),
+			"fd" | "freedman-diaconis" => Ok(BinRule::FreedmanDiaconis),
+			_ => match s.parse::<usize>() {
+				Ok(0) => Err(String::from("a histogram needs at least 1 bin")),
+				Ok(n) => Ok(BinRule::Fixed(n)),
+				Err(_) => Err(format!(
+					"{s} is not a bin rule (sturges, scott, fd or a number of bins)"
+				)),
+			},
+		}
+	}
+}
+
+impl std::fmt::Display for BinRule {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			BinRule::Sturges => write!(f, "sturges"),
+			BinRule::Scott => write!(f, "scott"),
+			BinRule::FreedmanDiaconis => write!(f, "fd"),
+			BinRule::Fixed(n) => write!(f, "{n}"),
+		}
+	}
+}
+
+impl BinRule {
+	/// number of bins for `count` values described by `analysis`,
+	/// the width based rules never give more bins than values
+	pub fn bins(&self, analysis: &Analysis, count: usize) -> usize {
+		if count == 0 {
+			return 1;
+		}
+
+		let range = match (analysis.min, analysis.max) {
+			(Some(min), Some(max)) => max - min,
+			_ => 0.0,
+		};
+		let cbrt = (count as Float).cbrt();
+
+		let width = match *self {
+			BinRule::Sturges => return sturges(count),
+			BinRule::Fixed(n) => return n.max(1),
+			BinRule::Scott => analysis.std.map(|std| 3.49 * std / cbrt),
+			BinRule::FreedmanDiaconis => analysis
+				.q1
+				.zip(analysis.q3)
+				.map(|(q1, q3)| 2.0 * (q3 - q1) / cbrt),
+		};
+
+		let bins = match width {
+			// an outlier far from the quartiles would make the range span millions of widths
+			Some(width) if width > 0.0 && range > 0.0 => {
+				((range / width).ceil() as usize).min(count)
+			}
+			_ => BinRule::Sturges.bins(analysis, count),
+		};
+
+		bins.max(1)
+	}
+}
+
+/// number of bins of sturges' rule for `count` values
+pub fn sturges(count: usize) -> usize {
+	(count.max(1) as Float).log2().ceil() as usize + 1
+}
+
+/// `counts[i]` is the number of values in `[edges[i], edges[i + 1])`,
+/// the last bin also includes its upper edge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+	pub edges: Vec<Float>,
+	pub counts: Vec<usize>,
+}
+
+impl Histogram {
+	/// evenly spaced edges from `min` to `max`, the last one is exactly `max`
+	pub fn edges(min: Float, max: Float, bins: usize) -> Vec<Float> {
+		let width = (max - min) / bins as Float;
+
+		(0..=bins)
+			.map(|i| match i {
+				i if i == bins => max,
+				i => min + width * i as Float,
+			})
+			.collect()
+	}
+
+	pub fn with_edges(values: impl IntoIterator<Item = Float>, edges: Vec<Float>) -> Self {
+		let mut counts = vec![0; edges.len().saturating_sub(1)];
+
+		for value in values {
+			if let Some(bin) = bin(value, &edges) {
+				counts[bin] += 1;
+			}
+		}
+
+		Self { edges, counts }
+	}
+
+	pub fn bin_width(&self) -> Float {
+		match (self.edges.first(), self.edges.last()) {
+			(Some(first), Some(last)) if !self.counts.is_empty() => {
+				(last - first) / self.counts.len() as Float
+			}
+			_ => 0.0,
+		}
+	}
+}
+
+/// one block character per bin, as high as its count
+pub fn sparkline(counts: &[usize]) -> String {
+	let max = counts.iter().copied().max().unwrap_or_default();
+
+	counts
+		.iter()
+		.map(|&count| {
+			if max == 0 {
+				return LEVELS[0];
+			}
+
+			LEVELS[(count * (LEVELS.len() - 1)).div_ceil(max)]
+		})
+		.collect()
+}
+
+fn bin(value: Float, edges: &[Float]) -> Option<usize> {
+	let (Some(&min), Some(&max)) = (edges.first(), edges.last()) else {
+		return None;
+	};
+	let bins = edges.len() - 1;
+
+	if bins == 0 || value.is_nan() || value < min || value > max {
+		return None;
+	}
+	if max == min {
+		return Some(0);
+	}
+
+	let bin = ((value - min) / (max - min) * bins as Float) as usize;
+
+	Some(bin.min(bins - 1))
+}
+
+/// histogram of every label sharing the same edges
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupedHistogram {
+	pub edges: Vec<Float>,
+	pub groups: BTreeMap<String, Vec<usize>>,
+}
+
+/// bin the non null values of a numeric `series`,
+/// `None` if the series is not numeric or has no value
+pub fn histogram(series: &Series, rule: BinRule) -> Option<Histogram> {
+	let edges = edges(series, rule)?;
+
+	Some(Histogram::with_edges(
+		values(series).into_iter().flatten(),
+		edges,
+	))
+}
+
+/// bin a numeric `series` separately for each value of the string series `labels`
+pub fn grouped_histogram(
+	series: &Series,
+	labels: &Series,
+	rule: BinRule,
+) -> PolarsResult<Option<GroupedHistogram>> {
+	let Some(edges) = edges(series, rule) else {
+		return Ok(None);
+	};
+
+	let mut grouped: BTreeMap<String, Vec<Float>> = BTreeMap::new();
+
+	for (value, label) in values(series).into_iter().zip(labels.str()?.into_iter()) {
+		if let (Some(value), Some(label)) = (value, label) {
+			grouped.entry(label.to_owned()).or_default().push(value);
+		}
+	}
+
+	let groups = grouped
+		.into_iter()
+		.map(|(label, values)| {
+			let histogram = Histogram::with_edges(values, edges.clone());
+			(label, histogram.counts)
+		})
+		.collect();
+
+	Ok(Some(GroupedHistogram { edges, groups }))
+}
+
+fn edges(series: &Series, rule: BinRule) -> Option<Vec<Float>> {
+	if !series.dtype().is_numeric() {
+		return None;
+	}
+
+	let analysis = Analysis::from(series);
+	let (min, max) = analysis.min.zip(analysis.max)?;
+	let count = series.len() - series.null_count();
+
+	Some(Histogram::edges(min, max, rule.bins(&analysis, count)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_bin_rule_parse() {
+		assert_eq!("sturges".parse(), Ok(BinRule::Sturges));
+		assert_eq!("scott".parse(), Ok(BinRule::Scott));
+		assert_eq!("fd".parse(), Ok(BinRule::FreedmanDiaconis));
+		assert_eq!("12".parse(), Ok(BinRule::Fixed(12)));
+		assert!("0".parse::<BinRule>().is_err());
+		assert!("many".parse::<BinRule>().is_err());
+	}
+
+	#[test]
+	fn test_bin_rule_bins() {
+		let s = Series::new("a".into(), &(1..=16).map(|x| x as f64).collect::<Vec<_>>());
+		let analysis = Analysis::from(&s);
+
+		assert_eq!(BinRule::Sturges.bins(&analysis, 16), 5);
+		assert_eq!(sturges(1), 1);
+		assert_eq!(sturges(1600), 12);
+		assert_eq!(BinRule::Fixed(3).bins(&analysis, 16), 3);
+		// h = 3.49 * 4.6098 / 16^(1/3) ~ 6.39, 15 / 6.39 -> 3
+		assert_eq!(BinRule::Scott.bins(&analysis, 16), 3);
+		// h = 2 * 8 / 16^(1/3) ~ 6.35, 15 / 6.35 -> 3
+		assert_eq!(BinRule::FreedmanDiaconis.bins(&analysis, 16), 3);
+	}
+
+	#[test]
+	fn test_bin_rule_bins_outlier() {
+		let s = Series::new(
+			"a".into(),
+			&(1..=16).map(|x| x as f64).chain([1e9]).collect::<Vec<_>>(),
+		);
+		let analysis = Analysis::from(&s);
+
+		assert_eq!(BinRule::FreedmanDiaconis.bins(&analysis, 17), 17);
+		assert!(BinRule::Scott.bins(&analysis, 17) <= 17);
+
+		let h = histogram(&s, BinRule::FreedmanDiaconis).unwrap();
+
+		assert_eq!(h.counts.len(), 17);
+		assert_eq!(h.counts.iter().sum::<usize>(), 17);
+	}
+
+	#[test]
+	fn test_histogram() {
+		let s = Series::new(
+			"a".into(),
+			&[Some(0.0), Some(1.0), None, Some(2.0), Some(3.0), Some(4.0)],
+		);
+
+		let h = histogram(&s, BinRule::Fixed(2)).unwrap();
+
+		assert_eq!(h.edges, vec![0.0, 2.0, 4.0]);
+		assert_eq!(h.counts, vec![2, 3]);
+		assert_eq!(h.bin_width(), 2.0);
+	}
+
+	#[test]
+	fn test_histogram_constant() {
+		let s = Series::new("a".into(), &[7, 7, 7]);
+
+		let h = histogram(&s, BinRule::Scott).unwrap();
+
+		assert_eq!(h.counts.iter().sum::<usize>(), 3);
+	}
+
+	#[test]
+	fn test_histogram_not_numeric() {
+		let s = Series::new("a".into(), &["a", "b"]);
+
+		assert_eq!(histogram(&s, BinRule::Sturges), None);
+	}
+
+	#[test]
+	fn test_sparkline() {
+		assert_eq!(sparkline(&[0, 1, 4, 8]), " ▁▄█");
+		assert_eq!(sparkline(&[0, 0]), "  ");
+		assert_eq!(sparkline(&[]), "");
+	}
+
+	#[test]
+	fn test_grouped_histogram() {
+		let s = Series::new("a".into(), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+		let labels = Series::new("l".into(), &["x", "y", "x", "y", "x"]);
+
+		let h = grouped_histogram(&s, &labels, BinRule::Fixed(2))
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(h.edges, vec![0.0, 2.0, 4.0]);
+		assert_eq!(h.groups["x"], vec![1, 2]);
+		assert_eq!(h.groups["y"], vec![1, 1]);
+	}
+}
@@ -1,10 +1,22 @@
 use polars::prelude::{DataType, Series};
+use serde::{Deserialize, Serialize};
 
 use float::Float;
 
-#[derive(Debug, Clone, PartialEq)]
+mod dtype;
+
+pub mod histogram;
+
+mod parallel;
+pub use parallel::par_analyze;
+
+mod report;
+pub use report::Report;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Analysis {
 	pub name: String,
+	#[serde(with = "dtype")]
 	pub dtype: DataType,
 
 	pub min: Option<Float>,
@@ -46,14 +58,7 @@ impl From<&Series> for Analysis {
 			return ret;
 		}
 
-		let mut arr = series
-			.cast(&DataType::Float32)
-			.expect("could not cast series to f32")
-			.f32()
-			.expect("could not extract series as f32 iterator")
-			.into_iter()
-			.flatten()
-			.collect::<Vec<Float>>();
+		let mut arr = values(series).into_iter().flatten().collect::<Vec<Float>>();
 
 		arr.sort_by(|a, b| a.total_cmp(b));
 
@@ -100,6 +105,28 @@ impl From<&Series> for Analysis {
 	}
 }
 
+/// values of a numeric series as `Float`, nulls included
+pub fn values(series: &Series) -> Vec<Option<Float>> {
+	series
+		.cast(&DataType::Float32)
+		.expect("could not cast series to f32")
+		.f32()
+		.expect("could not extract series as f32 iterator")
+		.into_iter()
+		.collect()
+}
+
+/// linearly interpolated quantile `q` between 0 and 1 of sorted values
+pub fn quantile(sorted: &[impl Copy + Into<f64>], q: f64) -> Option<f64> {
+	let last = sorted.len().checked_sub(1)?;
+
+	let rank = q.clamp(0.0, 1.0) * last as f64;
+	let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+	let (low_value, high_value) = (sorted[low].into(), sorted[high].into());
+
+	Some(low_value + (high_value - low_value) * (rank - low as f64))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -136,6 +163,17 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_quantile() {
+		let sorted: [Float; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+		assert_eq!(quantile(&sorted, 0.0), Some(1.0));
+		assert_eq!(quantile(&sorted, 0.5), Some(3.0));
+		assert!((quantile(&sorted, 0.95).unwrap() - 4.8).abs() < 1e-5);
+		assert_eq!(quantile(&sorted, 1.0), Some(5.0));
+		assert_eq!(quantile(&[] as &[f64], 0.5), None);
+	}
+
 	#[test]
 	fn test_analysis_basic() {
 		let name = String::from("a");
@@ -314,6 +352,18 @@ mod tests {
 		assert_eq!(a, polars_expect);
 	}
 
+	#[test]
+	fn test_analysis_serde() {
+		let s = Series::new("a".into(), &[Some(1), None, Some(3)]);
+
+		let a = Analysis::from(&s);
+
+		let json = serde_json::to_string(&a).unwrap();
+		assert!(json.contains(r#""dtype":"i32""#));
+
+		assert_eq!(serde_json::from_str::<Analysis>(&json).unwrap(), a);
+	}
+
 	#[test]
 	fn test_analysis_str() {
 		let name = String::from("a");
@@ -0,0 +1,46 @@
+use polars::prelude::{DataFrame, Series};
+use rayon::prelude::*;
+
+use crate::Analysis;
+
+/// analyze every column of `df` accepted by `filter` across all cores,
+/// the result keeps the column order of `df`
+pub fn par_analyze<F>(df: &DataFrame, filter: F) -> Vec<Analysis>
+where
+	F: Fn(&Series) -> bool + Sync,
+{
+	df.get_columns()
+		.par_iter()
+		.filter(|series| filter(series))
+		.map(Analysis::from)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polars::prelude::*;
+
+	#[test]
+	fn test_par_analyze_order() {
+		let df = DataFrame::new(
+			(0..64)
+				.map(|i| Series::new(format!("{i}").into(), &[i as f64, 1.0, -2.0]))
+				.chain(std::iter::once(Series::new("s".into(), &["a", "b", "c"])))
+				.collect(),
+		)
+		.unwrap();
+
+		let sequential = df
+			.get_columns()
+			.iter()
+			.filter(|series| series.dtype().is_numeric())
+			.map(Analysis::from)
+			.collect::<Vec<_>>();
+
+		let parallel = par_analyze(&df, |series| series.dtype().is_numeric());
+
+		assert_eq!(parallel.len(), 64);
+		assert_eq!(parallel, sequential);
+	}
+}
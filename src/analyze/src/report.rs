@@ -0,0 +1,71 @@
+use std::{fs::File, io, path::Path};
+
+use polars::prelude::DataFrame;
+use serde::{Deserialize, Serialize};
+
+use crate::{par_analyze, Analysis};
+
+/// analysis of every column of a dataset, stored as json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+	pub source: String,
+	pub rows: usize,
+	pub columns: Vec<Analysis>,
+}
+
+impl Report {
+	pub fn new(source: impl Into<String>, df: &DataFrame) -> Self {
+		Self {
+			source: source.into(),
+			rows: df.height(),
+			columns: par_analyze(df, |_| true),
+		}
+	}
+
+	pub fn get(&self, name: &str) -> Option<&Analysis> {
+		self.columns.iter().find(|analysis| analysis.name == name)
+	}
+
+	pub fn write(&self, path: &Path) -> io::Result<()> {
+		let file = File::create(path)?;
+
+		serde_json::to_writer_pretty(file, self)?;
+
+		Ok(())
+	}
+
+	pub fn read(path: &Path) -> io::Result<Self> {
+		let file = File::open(path)?;
+
+		Ok(serde_json::from_reader(io::BufReader::new(file))?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polars::prelude::*;
+
+	#[test]
+	fn test_report_write_read() {
+		let df = DataFrame::new(vec![
+			Series::new("label".into(), &["a", "b", "a"]),
+			Series::new("one".into(), &[1.0, 2.0, 3.0]),
+			Series::new("two".into(), &[Some(4), None, Some(6)]),
+		])
+		.unwrap();
+
+		let report = Report::new("memory", &df);
+
+		assert_eq!(report.rows, 3);
+		assert_eq!(report.columns.len(), 3);
+		assert_eq!(report.get("one").unwrap().mean, Some(2.0));
+		assert!(report.get("three").is_none());
+
+		let path = Path::new("/tmp/cargo_test_dslr_report.json");
+
+		report.write(path).unwrap();
+
+		assert_eq!(Report::read(path).unwrap(), report);
+	}
+}
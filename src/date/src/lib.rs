@@ -0,0 +1,184 @@
+use chrono::{Datelike, NaiveDate};
+use polars::prelude::*;
+
+pub const DEFAULT_FORMAT: &str = "%Y-%m-%d";
+
+/// numeric feature derived from a date
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+	Year,
+	Month,
+	DayOfYear,
+	/// from 0 for monday to 6 for sunday
+	Weekday,
+}
+
+impl std::fmt::Display for Part {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			Part::Year => write!(f, "year"),
+			Part::Month => write!(f, "month"),
+			Part::DayOfYear => write!(f, "day of year"),
+			Part::Weekday => write!(f, "weekday"),
+		}
+	}
+}
+
+impl std::str::FromStr for Part {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"year" => Ok(Part::Year),
+			"month" => Ok(Part::Month),
+			"day-of-year" | "day of year" => Ok(Part::DayOfYear),
+			"weekday" => Ok(Part::Weekday),
+			_ => Err(format!(
+				"{s} is not a date part (year, month, day-of-year or weekday)"
+			)),
+		}
+	}
+}
+
+impl Part {
+	pub const ALL: [Part; 4] = [Part::Year, Part::Month, Part::DayOfYear, Part::Weekday];
+
+	pub fn of(&self, date: NaiveDate) -> f64 {
+		match self {
+			Part::Year => date.year() as f64,
+			Part::Month => date.month() as f64,
+			Part::DayOfYear => date.ordinal() as f64,
+			Part::Weekday => date.weekday().num_days_from_monday() as f64,
+		}
+	}
+}
+
+/// a date column to expand, parsed with a chrono `format`
+#[derive(Debug, Clone)]
+pub struct Expansion {
+	column: String,
+	format: String,
+	parts: Vec<Part>,
+}
+
+impl Expansion {
+	/// every part of the dates of `column`
+	pub fn new(column: &str, format: &str) -> Self {
+		Self {
+			column: column.to_owned(),
+			format: format.to_owned(),
+			parts: Part::ALL.to_vec(),
+		}
+	}
+
+	pub fn parts(mut self, parts: &[Part]) -> Self {
+		self.parts = parts.to_vec();
+		self
+	}
+
+	/// name of the column of `part`, such as `Birthday year`
+	pub fn name(&self, part: Part) -> String {
+		format!("{} {part}", self.column)
+	}
+
+	/// date of each row, `None` when it is missing or does not match the format
+	pub fn dates(&self, df: &DataFrame) -> PolarsResult<Vec<Option<NaiveDate>>> {
+		Ok(df
+			.column(&self.column)?
+			.cast(&DataType::String)?
+			.str()?
+			.into_iter()
+			.map(|date| date.and_then(|date| parse(date, &self.format)))
+			.collect())
+	}
+
+	/// add a float column for each part, null where the date is invalid
+	pub fn apply(&self, df: &mut DataFrame) -> PolarsResult<()> {
+		let dates = self.dates(df)?;
+
+		for part in &self.parts {
+			let values = dates
+				.iter()
+				.map(|date| date.map(|date| part.of(date)))
+				.collect::<Vec<_>>();
+
+			df.with_column(Series::new(self.name(*part).into(), values))?;
+		}
+
+		Ok(())
+	}
+}
+
+pub fn parse(date: &str, format: &str) -> Option<NaiveDate> {
+	NaiveDate::parse_from_str(date.trim(), format).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_part_parse() {
+		assert_eq!("day-of-year".parse(), Ok(Part::DayOfYear));
+		for part in Part::ALL {
+			assert_eq!(part.to_string().parse(), Ok(part));
+		}
+		assert!("hour".parse::<Part>().is_err());
+	}
+
+	#[test]
+	fn test_expand() {
+		let mut df = DataFrame::new(vec![Series::new(
+			"Birthday".into(),
+			&[Some("2000-03-01"), Some("2000-13-01"), None, Some("oops")],
+		)])
+		.unwrap();
+
+		Expansion::new("Birthday", DEFAULT_FORMAT)
+			.apply(&mut df)
+			.unwrap();
+
+		let column = |name: &str| {
+			df.column(name)
+				.unwrap()
+				.f64()
+				.unwrap()
+				.into_iter()
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(column("Birthday year"), [Some(2000.0), None, None, None]);
+		assert_eq!(column("Birthday month"), [Some(3.0), None, None, None]);
+		// 31 days of january and 29 of february in 2000
+		assert_eq!(
+			column("Birthday day of year"),
+			[Some(61.0), None, None, None]
+		);
+		assert_eq!(column("Birthday weekday"), [Some(2.0), None, None, None]);
+	}
+
+	#[test]
+	fn test_expand_format() {
+		let mut df = DataFrame::new(vec![Series::new("d".into(), &["01/03/2000"])]).unwrap();
+
+		Expansion::new("d", "%d/%m/%Y")
+			.parts(&[Part::Month])
+			.apply(&mut df)
+			.unwrap();
+
+		assert_eq!(df.width(), 2);
+		assert_eq!(
+			df.column("d month").unwrap().f64().unwrap().get(0),
+			Some(3.0)
+		);
+	}
+
+	#[test]
+	fn test_expand_missing_column() {
+		let mut df = DataFrame::new(vec![Series::new("d".into(), &["2000-01-01"])]).unwrap();
+
+		assert!(Expansion::new("Birthday", DEFAULT_FORMAT)
+			.apply(&mut df)
+			.is_err());
+	}
+}
@@ -1,64 +1,261 @@
+use std::str::FromStr;
+
 use float::Float;
 use polars::prelude::*;
-use tabled::{builder::Builder, Table};
-
-use analyze::Analysis;
 
-use crate::Args;
+use crate::{
+	shape::{self, Shape},
+	Args,
+};
 
-type TableRecord<'s> = [&'s str; 9];
+pub const COLUMN: &str = "column";
+pub const TYPE: &str = "T";
 
-const HEADERS: TableRecord<'static> = [
-	"column", "T", "min", "max", "mean", "median", "q1", "q3", "std",
-];
+pub const DEFAULT_STATS: &str = "min,max,mean,median,q1,q3,std";
 
-pub fn compute(df: DataFrame, args: &Args) -> PolarsResult<(Table, Vec<DataType>)> {
-	let mut types = Vec::with_capacity(df.width());
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stat {
+	Count,
+	Null,
+	Min,
+	Max,
+	Mean,
+	Median,
+	Q1,
+	Q3,
+	Std,
+	Sum,
+	Skew,
+	Kurtosis,
+	Percentile(u8),
+}
 
-	let mut builder = Builder::default();
+impl std::fmt::Display for Stat {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			Stat::Count => write!(f, "count"),
+			Stat::Null => write!(f, "null"),
+			Stat::Min => write!(f, "min"),
+			Stat::Max => write!(f, "max"),
+			Stat::Mean => write!(f, "mean"),
+			Stat::Median => write!(f, "median"),
+			Stat::Q1 => write!(f, "q1"),
+			Stat::Q3 => write!(f, "q3"),
+			Stat::Std => write!(f, "std"),
+			Stat::Sum => write!(f, "sum"),
+			Stat::Skew => write!(f, "skew"),
+			Stat::Kurtosis => write!(f, "kurt"),
+			Stat::Percentile(p) => write!(f, "p{p}"),
+		}
+	}
+}
 
-	builder.push_record(HEADERS);
+impl FromStr for Stat {
+	type Err = String;
 
-	for series in df.get_columns() {
-		if !args.full && !series.dtype().is_numeric() {
-			continue;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"count" => Ok(Stat::Count),
+			"null" => Ok(Stat::Null),
+			"min" => Ok(Stat::Min),
+			"max" => Ok(Stat::Max),
+			"mean" => Ok(Stat::Mean),
+			"median" => Ok(Stat::Median),
+			"q1" => Ok(Stat::Q1),
+			"q3" => Ok(Stat::Q3),
+			"std" => Ok(Stat::Std),
+			"sum" => Ok(Stat::Sum),
+			"skew" => Ok(Stat::Skew),
+			"kurt" => Ok(Stat::Kurtosis),
+			_ => match s.strip_prefix('p').map(str::parse::<u8>) {
+				Some(Ok(p)) if p <= 100 => Ok(Stat::Percentile(p)),
+				_ => Err(format!(
+					"{s} is not a statistic (count, null, min, max, mean, median, q1, q3, std, sum, skew, kurt or p0 to p100)"
+				)),
+			},
 		}
+	}
+}
+
+impl Stat {
+	/// counts are printed without decimals
+	pub fn is_count(&self) -> bool {
+		matches!(self, Stat::Count | Stat::Null)
+	}
 
-		let analysis = Analysis::from(series);
+	fn needs_values(&self) -> bool {
+		matches!(
+			self,
+			Stat::Median | Stat::Q1 | Stat::Q3 | Stat::Skew | Stat::Kurtosis | Stat::Percentile(_)
+		)
+	}
+}
 
-		let name = truncate(&analysis.name, 10);
+pub struct Summary {
+	pub stats: Vec<Stat>,
+	pub rows: Vec<Row>,
+	/// sorted labels the shapes are split by
+	pub labels: Vec<String>,
+	/// one per row with `--shape`, empty otherwise
+	pub shapes: Vec<Option<Shape>>,
+}
 
-		let record: TableRecord = [
-			&name,
-			&analysis.dtype.to_string(),
-			&to_string(analysis.min, args),
-			&to_string(analysis.max, args),
-			&to_string(analysis.mean, args),
-			&to_string(analysis.median, args),
-			&to_string(analysis.q1, args),
-			&to_string(analysis.q3, args),
-			&to_string(analysis.std, args),
-		];
-		builder.push_record(record);
+pub struct Row {
+	pub name: String,
+	pub dtype: DataType,
+	pub values: Vec<Option<Float>>,
+}
 
-		types.push(analysis.dtype);
+impl Summary {
+	pub fn headers(&self) -> impl Iterator<Item = String> + '_ {
+		self.stats.iter().map(Stat::to_string)
 	}
 
-	Ok((builder.build(), types))
+	pub fn cells<'s>(&'s self, row: &'s Row, args: &'s Args) -> impl Iterator<Item = String> + 's {
+		self.stats
+			.iter()
+			.zip(row.values.iter())
+			.map(|(stat, n)| to_string(*stat, *n, args))
+	}
 }
 
-fn truncate(s: &str, len: usize) -> String {
-	if s.len() <= len {
-		return s.to_owned();
+pub fn compute(df: DataFrame, labels: Option<&Series>, args: &Args) -> PolarsResult<Summary> {
+	let analyses = analyze::par_analyze(&df, |series| args.full || series.dtype().is_numeric());
+
+	let needs_values = args.stats.iter().any(Stat::needs_values);
+
+	let mut rows = Vec::with_capacity(analyses.len());
+
+	for analysis in analyses {
+		let series = df.column(&analysis.name)?;
+
+		let sorted = if needs_values && analysis.dtype.is_numeric() {
+			let mut values = analyze::values(series)
+				.into_iter()
+				.flatten()
+				.collect::<Vec<_>>();
+			values.sort_by(|a, b| a.total_cmp(b));
+			values
+		} else {
+			Vec::new()
+		};
+
+		let values = args
+			.stats
+			.iter()
+			.map(|stat| match stat {
+				Stat::Count => Some((series.len() - series.null_count()) as Float),
+				Stat::Null => Some(series.null_count() as Float),
+				Stat::Min => analysis.min,
+				Stat::Max => analysis.max,
+				Stat::Mean => analysis.mean,
+				Stat::Median => percentile(&sorted, 50),
+				Stat::Q1 => percentile(&sorted, 25),
+				Stat::Q3 => percentile(&sorted, 75),
+				Stat::Std => analysis.std,
+				Stat::Sum => analysis.sum,
+				Stat::Skew => moment(&sorted, analysis.mean, analysis.std, 3),
+				Stat::Kurtosis => moment(&sorted, analysis.mean, analysis.std, 4).map(|m| m - 3.0),
+				Stat::Percentile(p) => percentile(&sorted, *p),
+			})
+			.collect();
+
+		rows.push(Row {
+			name: analysis.name,
+			dtype: analysis.dtype,
+			values,
+		});
 	}
 
-	format!("{}…", &s[..len - 1])
+	let (labels, shapes) = if args.shape {
+		let names = rows.iter().map(|row| row.name.clone()).collect::<Vec<_>>();
+		shape::shapes(&df, &names, labels)?
+	} else {
+		(Vec::new(), Vec::new())
+	};
+
+	Ok(Summary {
+		stats: args.stats.clone(),
+		rows,
+		labels,
+		shapes,
+	})
 }
 
-fn to_string(n: Option<Float>, args: &Args) -> String {
+/// linearly interpolated, like numpy and polars by default,
+/// for the quartiles and the median to match the same percentiles
+fn percentile(sorted: &[Float], p: u8) -> Option<Float> {
+	analyze::quantile(sorted, p as f64 / 100.0).map(|x| x as Float)
+}
+
+/// standardized moment of order `k`
+fn moment(values: &[Float], mean: Option<Float>, std: Option<Float>, k: i32) -> Option<Float> {
+	let (mean, std) = mean.zip(std)?;
+
+	if values.is_empty() || std == 0.0 {
+		return None;
+	}
+
+	let sum: Float = values.iter().map(|x| ((x - mean) / std).powi(k)).sum();
+
+	Some(sum / values.len() as Float)
+}
+
+pub fn to_string(stat: Stat, n: Option<Float>, args: &Args) -> String {
 	let Some(n) = n else {
 		return String::new();
 	};
 
+	if stat.is_count() {
+		return format!("{n}");
+	}
+
 	format!("{:.1$}", n, args.round as usize)
 }
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser;
+
+	use super::*;
+
+	#[test]
+	fn test_stat_parse() {
+		assert_eq!("count".parse(), Ok(Stat::Count));
+		assert_eq!("p5".parse(), Ok(Stat::Percentile(5)));
+		assert_eq!("p100".parse(), Ok(Stat::Percentile(100)));
+		assert!("p101".parse::<Stat>().is_err());
+		assert!("average".parse::<Stat>().is_err());
+
+		for stat in DEFAULT_STATS.split(',') {
+			assert_eq!(stat.parse::<Stat>().unwrap().to_string(), stat);
+		}
+	}
+
+	#[test]
+	fn test_quartiles() {
+		let df = DataFrame::new(vec![Series::new(
+			"x".into(),
+			&[4.0, 1.0, 3.0, 2.0, 10.0, 6.0],
+		)])
+		.unwrap();
+		let args = Args::parse_from(["describe", "--stats", "q1,p25,median,p50,q3,p75"]);
+
+		let summary = compute(df, None, &args).unwrap();
+		let values = &summary.rows[0].values;
+
+		assert_eq!(values[0], values[1]);
+		assert_eq!(values[2], values[3]);
+		assert_eq!(values[4], values[5]);
+		assert_eq!(values[2], Some(3.5));
+	}
+
+	#[test]
+	fn test_moment() {
+		let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+		let std = Some(std::f32::consts::SQRT_2);
+
+		assert!(moment(&values, Some(3.0), std, 3).unwrap().abs() < 1e-5);
+		assert_eq!(moment(&values, Some(3.0), Some(0.0), 3), None);
+	}
+}
@@ -0,0 +1,280 @@
+use float::Float;
+use polars::prelude::{DataFrame, DataType};
+use tabled::{
+	builder::Builder,
+	settings::{object::Rows, themes::Colorization, Color},
+};
+
+use crate::{
+	compute::{Row, Summary, COLUMN, TYPE},
+	present::section,
+	terminal, Args,
+};
+
+const SIDE: &str = "";
+const NOTE: &str = "note";
+
+/// name and dtype of a column, numeric or not
+pub type Column = (String, DataType);
+
+pub fn columns(df: &DataFrame) -> Vec<Column> {
+	df.get_columns()
+		.iter()
+		.map(|series| (series.name().to_string(), series.dtype().clone()))
+		.collect()
+}
+
+/// a column of either dataset, aligned by name
+pub struct Aligned<'s> {
+	pub name: &'s str,
+	/// dtype of the column in each dataset, `None` on the side lacking it
+	pub dtypes: (Option<&'s DataType>, Option<&'s DataType>),
+	/// summary of the column on each side, `None` when it was not described
+	pub left: Option<&'s Row>,
+	pub right: Option<&'s Row>,
+}
+
+impl Aligned<'_> {
+	fn note(&self, names: (&str, &str)) -> String {
+		match self.dtypes {
+			(Some(_), None) => format!("only in {}", names.0),
+			(None, Some(_)) => format!("only in {}", names.1),
+			(Some(left), Some(right)) if left != right => format!("dtype {left} -> {right}"),
+			_ => String::new(),
+		}
+	}
+}
+
+/// columns of `left` in order, then the columns only in `right`,
+/// skipping the ones neither summary describes unless they changed
+pub fn align<'s>(
+	columns: (&'s [Column], &'s [Column]),
+	left: &'s Summary,
+	right: &'s Summary,
+) -> Vec<Aligned<'s>> {
+	let dtype = |columns: &'s [Column], name: &str| {
+		columns
+			.iter()
+			.find(|(column, _)| column == name)
+			.map(|(_, dtype)| dtype)
+	};
+	let row = |summary: &'s Summary, name: &str| summary.rows.iter().find(|row| row.name == name);
+
+	columns
+		.0
+		.iter()
+		.chain(
+			columns
+				.1
+				.iter()
+				.filter(|(name, _)| dtype(columns.0, name).is_none()),
+		)
+		.map(|(name, _)| Aligned {
+			name,
+			dtypes: (dtype(columns.0, name), dtype(columns.1, name)),
+			left: row(left, name),
+			right: row(right, name),
+		})
+		.filter(|aligned| {
+			aligned.left.is_some()
+				|| aligned.right.is_some()
+				|| aligned.dtypes.0 != aligned.dtypes.1
+		})
+		.collect()
+}
+
+/// `right - left` and `(right - left) / |left|`
+pub fn difference(left: Option<Float>, right: Option<Float>) -> (Option<Float>, Option<Float>) {
+	let Some((left, right)) = left.zip(right) else {
+		return (None, None);
+	};
+
+	let absolute = right - left;
+	let relative = (left != 0.0).then(|| absolute / left.abs());
+
+	(Some(absolute), relative)
+}
+
+/// render both summaries column by column: the statistics of each dataset,
+/// their absolute then relative difference, and what changed between them
+pub fn present(
+	columns: (&[Column], &[Column]),
+	left: &Summary,
+	right: &Summary,
+	names: (&str, &str),
+	args: &Args,
+) -> String {
+	let colored = args.output.is_none();
+	let round = args.round as usize;
+
+	let mut builder = Builder::default();
+	builder.push_record(
+		[COLUMN, TYPE, SIDE]
+			.into_iter()
+			.map(String::from)
+			.chain(left.headers())
+			.chain([NOTE.to_string()]),
+	);
+
+	let mut notes = Vec::new();
+	for aligned in align(columns, left, right) {
+		let name = match args.name_width {
+			Some(width) => terminal::truncate(aligned.name, width),
+			None => aligned.name.to_owned(),
+		};
+		let note = aligned.note(names);
+
+		for (side, dtype, row) in [
+			(names.0, aligned.dtypes.0, aligned.left),
+			(names.1, aligned.dtypes.1, aligned.right),
+		] {
+			let Some(dtype) = dtype else {
+				continue;
+			};
+
+			// a column that was not described, like a numeric one turned into strings
+			let cells = match row {
+				Some(row) => left.cells(row, args).collect(),
+				None => vec![String::new(); left.stats.len()],
+			};
+
+			builder.push_record(
+				[name.clone(), dtype.to_string(), side.to_owned()]
+					.into_iter()
+					.chain(cells)
+					.chain([note.clone()]),
+			);
+			notes.push(!note.is_empty());
+		}
+
+		let (Some(a), Some(b)) = (aligned.left, aligned.right) else {
+			continue;
+		};
+
+		let differences = left
+			.stats
+			.iter()
+			.zip(a.values.iter().zip(&b.values))
+			.map(|(stat, (a, b))| (*stat, difference(*a, *b)))
+			.collect::<Vec<_>>();
+
+		builder.push_record(
+			[name.clone(), String::new(), String::from("Δ")]
+				.into_iter()
+				.chain(
+					differences
+						.iter()
+						.map(|(stat, (absolute, _))| match absolute {
+							Some(absolute) if stat.is_count() => format!("{absolute:+}"),
+							Some(absolute) => format!("{absolute:+.0$}", round),
+							None => String::new(),
+						}),
+				)
+				.chain([note.clone()]),
+		);
+		builder.push_record(
+			[name, String::new(), String::from("Δ%")]
+				.into_iter()
+				.chain(differences.iter().map(|(_, (_, relative))| {
+					relative
+						.map(|relative| format!("{:+.1$}%", relative * 100.0, round))
+						.unwrap_or_default()
+				}))
+				.chain([note.clone()]),
+		);
+		notes.extend([!note.is_empty(); 2]);
+	}
+
+	let mut table = builder.build();
+	if colored {
+		for (i, flagged) in notes.into_iter().enumerate() {
+			if flagged {
+				table.with(Colorization::exact(
+					[Color::new("\u{1b}[33m", "\u{1b}[0m")],
+					Rows::single(1 + i),
+				));
+			}
+		}
+	}
+
+	section(&format!("{} vs {}", names.0, names.1), table, colored)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::compute::Stat;
+
+	fn summary(rows: &[(&str, DataType, Float)]) -> Summary {
+		Summary {
+			stats: vec![Stat::Mean],
+			rows: rows
+				.iter()
+				.map(|(name, dtype, mean)| Row {
+					name: name.to_string(),
+					dtype: dtype.clone(),
+					values: vec![Some(*mean)],
+				})
+				.collect(),
+			labels: Vec::new(),
+			shapes: Vec::new(),
+		}
+	}
+
+	fn columns(columns: &[(&str, DataType)]) -> Vec<Column> {
+		columns
+			.iter()
+			.map(|(name, dtype)| (name.to_string(), dtype.clone()))
+			.collect()
+	}
+
+	#[test]
+	fn test_align() {
+		let left = summary(&[("a", DataType::Float64, 1.0), ("b", DataType::Float64, 2.0)]);
+		let right = summary(&[("c", DataType::Float64, 3.0), ("b", DataType::Int64, 2.0)]);
+		let schemas = (
+			columns(&[("a", DataType::Float64), ("b", DataType::Float64)]),
+			columns(&[("c", DataType::Float64), ("b", DataType::Int64)]),
+		);
+
+		let aligned = align((&schemas.0, &schemas.1), &left, &right);
+
+		assert_eq!(
+			aligned.iter().map(|a| a.name).collect::<Vec<_>>(),
+			["a", "b", "c"]
+		);
+		assert_eq!(
+			aligned
+				.iter()
+				.map(|a| a.note(("x.csv", "y.csv")))
+				.collect::<Vec<_>>(),
+			["only in x.csv", "dtype f64 -> i64", "only in y.csv"]
+		);
+	}
+
+	#[test]
+	fn test_align_dtype_change() {
+		// strings are not described without --full
+		let left = summary(&[("a", DataType::Float64, 1.0)]);
+		let right = summary(&[]);
+		let schemas = (
+			columns(&[("a", DataType::Float64), ("b", DataType::String)]),
+			columns(&[("a", DataType::String), ("b", DataType::String)]),
+		);
+
+		let aligned = align((&schemas.0, &schemas.1), &left, &right);
+
+		assert_eq!(aligned.len(), 1);
+		assert_eq!(aligned[0].name, "a");
+		assert!(aligned[0].left.is_some() && aligned[0].right.is_none());
+		assert_eq!(aligned[0].note(("x.csv", "y.csv")), "dtype f64 -> str");
+	}
+
+	#[test]
+	fn test_difference() {
+		assert_eq!(difference(Some(2.0), Some(3.0)), (Some(1.0), Some(0.5)));
+		assert_eq!(difference(Some(-2.0), Some(-3.0)), (Some(-1.0), Some(-0.5)));
+		assert_eq!(difference(Some(0.0), Some(3.0)), (Some(3.0), None));
+		assert_eq!(difference(None, Some(3.0)), (None, None));
+	}
+}
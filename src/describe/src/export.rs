@@ -0,0 +1,276 @@
+use polars::prelude::*;
+use serde_json::{Map, Value};
+
+use crate::{
+	compute::{self, Summary, COLUMN},
+	Args, Format,
+};
+
+const TYPE: &str = "type";
+
+/// render the summary in a machine readable `format` without any escape code
+pub fn export(summary: &Summary, format: Format, args: &Args) -> PolarsResult<String> {
+	match format {
+		Format::Table => unreachable!("tables are rendered by present"),
+		Format::Csv => csv(summary, args),
+		Format::Json => json(summary, args),
+		Format::Markdown => Ok(markdown(summary, args)),
+		Format::Latex => Ok(latex(summary, args)),
+		Format::Html => Ok(html(summary, args)),
+	}
+}
+
+fn headers(summary: &Summary) -> impl Iterator<Item = String> + '_ {
+	[COLUMN, TYPE]
+		.into_iter()
+		.map(String::from)
+		.chain(summary.headers())
+}
+
+fn records<'s>(summary: &'s Summary, args: &'s Args) -> impl Iterator<Item = Vec<String>> + 's {
+	summary.rows.iter().map(|row| {
+		[row.name.clone(), row.dtype.to_string()]
+			.into_iter()
+			.chain(summary.cells(row, args))
+			.collect()
+	})
+}
+
+fn csv(summary: &Summary, args: &Args) -> PolarsResult<String> {
+	let mut wtr = csv::Writer::from_writer(Vec::new());
+
+	wtr.write_record(headers(summary)).map_err(to_polars)?;
+	for record in records(summary, args) {
+		wtr.write_record(record).map_err(to_polars)?;
+	}
+
+	let bytes = wtr.into_inner().map_err(|e| to_polars(e.into_error()))?;
+
+	Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn json(summary: &Summary, args: &Args) -> PolarsResult<String> {
+	let rows = summary
+		.rows
+		.iter()
+		.map(|row| {
+			let mut object = Map::new();
+
+			object.insert(COLUMN.to_string(), Value::from(row.name.as_str()));
+			object.insert(TYPE.to_string(), Value::from(row.dtype.to_string()));
+
+			for (stat, value) in summary.stats.iter().zip(row.values.iter()) {
+				let value = match value {
+					Some(n) if stat.is_count() => Value::from(*n as u64),
+					// rounded through the text of the other formats, without the noise of f32
+					Some(_) => compute::to_string(*stat, *value, args)
+						.parse::<f64>()
+						.map_or(Value::Null, Value::from),
+					None => Value::Null,
+				};
+				object.insert(stat.to_string(), value);
+			}
+
+			Value::Object(object)
+		})
+		.collect::<Vec<_>>();
+
+	let mut json = serde_json::to_string_pretty(&rows).map_err(to_polars)?;
+	json.push('\n');
+
+	Ok(json)
+}
+
+fn markdown(summary: &Summary, args: &Args) -> String {
+	let escape = |s: &str| s.replace('|', "\\|");
+
+	let mut out = String::new();
+
+	let headers = headers(summary).collect::<Vec<_>>();
+	out += &format!("| {} |\n", headers.join(" | "));
+	out += &format!(
+		"|{}|\n",
+		(0..headers.len())
+			.map(|i| if i < 2 { " :--- " } else { " ---: " })
+			.collect::<Vec<_>>()
+			.join("|")
+	);
+
+	for record in records(summary, args) {
+		let record = record.iter().map(|s| escape(s)).collect::<Vec<_>>();
+		out += &format!("| {} |\n", record.join(" | "));
+	}
+
+	out
+}
+
+fn latex(summary: &Summary, args: &Args) -> String {
+	let escape = |s: &str| {
+		s.chars().fold(String::new(), |mut acc, c| {
+			match c {
+				'&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+					acc.push('\\');
+					acc.push(c);
+				}
+				'~' => acc += "\\textasciitilde{}",
+				'^' => acc += "\\textasciicircum{}",
+				'\\' => acc += "\\textbackslash{}",
+				_ => acc.push(c),
+			}
+			acc
+		})
+	};
+
+	let headers = headers(summary).map(|s| escape(&s)).collect::<Vec<_>>();
+
+	let mut out = format!(
+		"\\begin{{tabular}}{{ll{}}}\n",
+		"r".repeat(summary.stats.len())
+	);
+	out += "\\hline\n";
+	out += &format!("{} \\\\\n", headers.join(" & "));
+	out += "\\hline\n";
+
+	for record in records(summary, args) {
+		let record = record.iter().map(|s| escape(s)).collect::<Vec<_>>();
+		out += &format!("{} \\\\\n", record.join(" & "));
+	}
+
+	out += "\\hline\n";
+	out += "\\end{tabular}\n";
+
+	out
+}
+
+fn html(summary: &Summary, args: &Args) -> String {
+	let escape = |s: &str| {
+		s.replace('&', "&amp;")
+			.replace('<', "&lt;")
+			.replace('>', "&gt;")
+			.replace('"', "&quot;")
+	};
+
+	let mut out = String::from("<table>\n<thead>\n<tr>");
+	for header in headers(summary) {
+		out += &format!("<th>{}</th>", escape(&header));
+	}
+	out += "</tr>\n</thead>\n<tbody>\n";
+
+	for record in records(summary, args) {
+		out += "<tr>";
+		for cell in record {
+			out += &format!("<td>{}</td>", escape(&cell));
+		}
+		out += "</tr>\n";
+	}
+
+	out += "</tbody>\n</table>\n";
+
+	out
+}
+
+fn to_polars(e: impl std::error::Error) -> PolarsError {
+	PolarsError::ComputeError(e.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser;
+	use polars::prelude::DataType;
+
+	use super::*;
+	use crate::compute::{Row, Stat};
+
+	fn summary() -> Summary {
+		Summary {
+			stats: vec![Stat::Count, Stat::Mean],
+			rows: vec![
+				Row {
+					name: String::from("a"),
+					dtype: DataType::Float64,
+					values: vec![Some(3.0), Some(1.23456)],
+				},
+				Row {
+					name: String::from("b|<&_"),
+					dtype: DataType::String,
+					values: vec![Some(2.0), None],
+				},
+			],
+			labels: Vec::new(),
+			shapes: Vec::new(),
+		}
+	}
+
+	fn export(format: Format) -> String {
+		super::export(&summary(), format, &Args::parse_from(["describe"])).unwrap()
+	}
+
+	#[test]
+	fn test_csv() {
+		assert_eq!(
+			export(Format::Csv),
+			"column,type,count,mean\na,f64,3,1.23\nb|<&_,str,2,\n"
+		);
+	}
+
+	#[test]
+	fn test_json() {
+		assert_eq!(
+			export(Format::Json),
+			r#"[
+  {
+    "column": "a",
+    "type": "f64",
+    "count": 3,
+    "mean": 1.23
+  },
+  {
+    "column": "b|<&_",
+    "type": "str",
+    "count": 2,
+    "mean": null
+  }
+]
+"#
+		);
+	}
+
+	#[test]
+	fn test_markdown() {
+		assert_eq!(
+			export(Format::Markdown),
+			"| column | type | count | mean |\n\
+			| :--- | :--- | ---: | ---: |\n\
+			| a | f64 | 3 | 1.23 |\n\
+			| b\\|<&_ | str | 2 |  |\n"
+		);
+	}
+
+	#[test]
+	fn test_latex() {
+		assert_eq!(
+			export(Format::Latex),
+			"\\begin{tabular}{llrr}\n\
+			\\hline\n\
+			column & type & count & mean \\\\\n\
+			\\hline\n\
+			a & f64 & 3 & 1.23 \\\\\n\
+			b|<\\&\\_ & str & 2 &  \\\\\n\
+			\\hline\n\
+			\\end{tabular}\n"
+		);
+	}
+
+	#[test]
+	fn test_html() {
+		assert_eq!(
+			export(Format::Html),
+			"<table>\n<thead>\n\
+			<tr><th>column</th><th>type</th><th>count</th><th>mean</th></tr>\n\
+			</thead>\n<tbody>\n\
+			<tr><td>a</td><td>f64</td><td>3</td><td>1.23</td></tr>\n\
+			<tr><td>b|&lt;&amp;_</td><td>str</td><td>2</td><td></td></tr>\n\
+			</tbody>\n</table>\n"
+		);
+	}
+}
@@ -0,0 +1,253 @@
+use float::Float;
+use model::Model;
+use polars::prelude::*;
+use tabled::{
+	builder::Builder,
+	settings::{object::Cell, themes::Colorization, Color},
+	Table,
+};
+
+use crate::{present::section, Args};
+
+const FEATURE: &str = "feature";
+const INFLUENTIAL: usize = 3;
+
+/// a trained model with the name of its features
+pub struct Inspection {
+	/// sorted labels of the model
+	pub labels: Vec<String>,
+	pub features: Vec<String>,
+	/// `weights[class][feature]`
+	pub weights: Vec<Vec<Float>>,
+	pub means: Vec<Float>,
+	pub factors: Vec<(Float, Float)>,
+	/// indexes of the features with the largest absolute weight of each class
+	pub influential: Vec<Vec<usize>>,
+	pub warnings: Vec<String>,
+}
+
+/// the features of a model are the float columns of its training dataset,
+/// they are numbered when `df` does not have as many
+pub fn inspect(model: &Model, df: Option<&DataFrame>) -> Inspection {
+	let len = model.normalization_factors.len();
+	let mut warnings = Vec::new();
+
+	let columns = df
+		.map(|df| {
+			df.get_columns()
+				.iter()
+				.filter(|series| series.dtype().is_float())
+				.map(|series| series.name().to_string())
+				.collect::<Vec<_>>()
+		})
+		.unwrap_or_default();
+
+	let features = if columns.len() == len {
+		columns
+	} else {
+		if df.is_some() {
+			warnings.push(format!(
+				"the model has {len} features but the dataset has {} float columns, features are numbered",
+				columns.len()
+			));
+		}
+		(0..len).map(|i| format!("#{i}")).collect()
+	};
+
+	let mut labels = model.weights.keys().cloned().collect::<Vec<_>>();
+	labels.sort();
+
+	let weights = labels
+		.iter()
+		.map(|label| model.weights[label].clone())
+		.collect::<Vec<_>>();
+
+	let influential = weights
+		.iter()
+		.map(|weights| {
+			let mut order = (0..weights.len())
+				.filter(|&i| weights[i].is_finite())
+				.collect::<Vec<_>>();
+			order.sort_by(|&a, &b| weights[b].abs().total_cmp(&weights[a].abs()));
+			order.truncate(INFLUENTIAL);
+			order
+		})
+		.collect();
+
+	for (feature, (offset, scale)) in features.iter().zip(&model.normalization_factors) {
+		if *scale == 0.0 {
+			warnings.push(format!(
+				"{feature} has a zero scale factor, normalizing it divides by zero"
+			));
+		}
+		if !offset.is_finite() || !scale.is_finite() {
+			warnings.push(format!("{feature} has a non finite normalization factor"));
+		}
+	}
+
+	for (feature, mean) in features.iter().zip(&model.means) {
+		if !mean.is_finite() {
+			warnings.push(format!("{feature} has a non finite mean"));
+		}
+	}
+
+	for (label, weights) in labels.iter().zip(&weights) {
+		for (feature, weight) in features.iter().zip(weights) {
+			if !weight.is_finite() {
+				warnings.push(format!("{label} has a non finite weight for {feature}"));
+			}
+		}
+		if weights.iter().all(|weight| *weight == 0.0) {
+			warnings.push(format!("{label} has only zero weights"));
+		}
+	}
+
+	Inspection {
+		labels,
+		features,
+		weights,
+		means: model.means.clone(),
+		factors: model.normalization_factors.clone(),
+		influential,
+		warnings,
+	}
+}
+
+/// render the model as a table of its features with the weight of each class,
+/// the most influential features of each class, then the warnings
+pub fn present(inspection: &Inspection, args: &Args) -> String {
+	let colored = args.output.is_none();
+	let round = args.round as usize;
+
+	let mut out = String::new();
+
+	let mut builder = Builder::default();
+	builder.push_record(
+		[FEATURE, "mean", "offset", "scale"]
+			.into_iter()
+			.map(String::from)
+			.chain(inspection.labels.iter().cloned()),
+	);
+	for (i, feature) in inspection.features.iter().enumerate() {
+		let (offset, scale) = inspection.factors[i];
+
+		builder.push_record(
+			[
+				feature.clone(),
+				format!("{:.1$}", inspection.means[i], round),
+				format!("{offset:.0$}", round),
+				format!("{scale:.0$}", round),
+			]
+			.into_iter()
+			.chain(
+				inspection
+					.weights
+					.iter()
+					.map(|weights| format!("{:.1$}", weights[i], round)),
+			),
+		);
+	}
+
+	let mut table = builder.build();
+	if colored {
+		highlight(&mut table, inspection);
+	}
+	out += &section("weights", table, colored);
+
+	let mut builder = Builder::default();
+	builder.push_record(
+		["class"]
+			.into_iter()
+			.map(String::from)
+			.chain((1..=INFLUENTIAL).map(|rank| format!("#{rank}"))),
+	);
+	for (class, label) in inspection.labels.iter().enumerate() {
+		builder.push_record([label.clone()].into_iter().chain(
+			inspection.influential[class].iter().map(|&i| {
+				format!(
+					"{} ({:+.2$})",
+					inspection.features[i], inspection.weights[class][i], round
+				)
+			}),
+		));
+	}
+	out += &section("most influential features", builder.build(), colored);
+
+	for warning in &inspection.warnings {
+		if colored {
+			out += &format!("\u{1b}[1;33mwarning\u{1b}[0m: {warning}\n");
+		} else {
+			out += &format!("warning: {warning}\n");
+		}
+	}
+
+	out
+}
+
+/// color the most influential weights of each class,
+/// green when positive and red when negative
+fn highlight(table: &mut Table, inspection: &Inspection) {
+	for (class, influential) in inspection.influential.iter().enumerate() {
+		for &i in influential {
+			let color = if inspection.weights[class][i] < 0.0 {
+				Color::new("\u{1b}[1;31m", "\u{1b}[0m")
+			} else {
+				Color::new("\u{1b}[1;32m", "\u{1b}[0m")
+			};
+
+			table.with(Colorization::exact([color], Cell::from((1 + i, 4 + class))));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn model() -> Model {
+		Model {
+			label_name: String::from("house"),
+			weights: [
+				(String::from("b"), vec![0.5, -2.0, Float::NAN]),
+				(String::from("a"), vec![1.0, 0.1, -3.0]),
+			]
+			.into_iter()
+			.collect(),
+			normalization_factors: vec![(0.0, 1.0), (1.0, 0.0), (2.0, 3.0)],
+			means: vec![0.0, 1.0, 2.0],
+			date: None,
+		}
+	}
+
+	#[test]
+	fn test_inspect() {
+		let df = DataFrame::new(vec![
+			Series::new("x".into(), &[1.0]),
+			Series::new("n".into(), &[1]),
+			Series::new("y".into(), &[1.0]),
+			Series::new("z".into(), &[1.0]),
+		])
+		.unwrap();
+
+		let inspection = inspect(&model(), Some(&df));
+
+		assert_eq!(inspection.labels, ["a", "b"]);
+		assert_eq!(inspection.features, ["x", "y", "z"]);
+		assert_eq!(inspection.influential, [vec![2, 0, 1], vec![1, 0]]);
+		assert_eq!(
+			inspection.warnings,
+			[
+				"y has a zero scale factor, normalizing it divides by zero",
+				"b has a non finite weight for z",
+			]
+		);
+	}
+
+	#[test]
+	fn test_inspect_numbered() {
+		let inspection = inspect(&model(), None);
+
+		assert_eq!(inspection.features, ["#0", "#1", "#2"]);
+		assert_eq!(inspection.warnings.len(), 2);
+	}
+}
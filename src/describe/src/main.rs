@@ -1,18 +1,50 @@
 mod compute;
+mod diff;
+mod export;
+mod inspect;
+mod missing;
 mod present;
+mod rotate;
+mod shape;
+mod terminal;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use clap::Parser;
-use polars::error::PolarsResult;
+use analyze::Report;
+use clap::{
+	error::ErrorKind, parser::ValueSource, ArgGroup, CommandFactory, FromArgMatches, Parser,
+	ValueEnum,
+};
+use compute::Stat;
+use date::Expansion;
+use model::Model;
+use polars::prelude::{polars_err, DataFrame};
+use select::Selection;
+
+#[derive(ValueEnum, Default, Clone, Copy, PartialEq)]
+pub enum Format {
+	#[default]
+	Table,
+	Csv,
+	Json,
+	Markdown,
+	Latex,
+	Html,
+}
 
 #[derive(Parser)]
 #[command(about)]
+#[command(group(ArgGroup::new("mode").args(["missing", "rotate", "model", "diff"]).multiple(false)))]
+#[command(group(ArgGroup::new("split").args(["shape", "missing"])))]
 pub struct Args {
 	/// path to the csv file to describe
 	#[clap(default_value = "datasets/train.csv")]
 	path: PathBuf,
 
+	/// second csv file, compared to the first one with --diff
+	#[clap(requires = "diff")]
+	other: Option<PathBuf>,
+
 	/// print a full summary of the csv file (including all columns)
 	#[clap(long, short)]
 	full: bool,
@@ -20,18 +52,259 @@ pub struct Args {
 	/// round the output to the given number of decimal places
 	#[clap(long, short, default_value = "2")]
 	round: u8,
-	/*
-	/// rotate the output 90 degrees
-	#[clap(short, long)]
+
+	/// cut column names to this width
+	/// (by default only when the table does not fit in the terminal)
+	#[clap(long, short = 'w')]
+	name_width: Option<usize>,
+
+	/// only describe the columns matching these comma separated names or globs
+	#[clap(long, short, value_delimiter = ',')]
+	columns: Vec<String>,
+
+	/// never describe the columns matching these comma separated names or globs
+	#[clap(long, short = 'x', value_delimiter = ',')]
+	exclude: Vec<String>,
+
+	/// match --columns and --exclude as regexes instead of globs
+	#[clap(long)]
+	regex: bool,
+
+	/// statistics to show, in order
+	/// (count, null, min, max, mean, median, q1, q3, std, sum, skew, kurt or p0 to p100)
+	#[clap(long, short, value_delimiter = ',', default_value = compute::DEFAULT_STATS)]
+	stats: Vec<Stat>,
+
+	/// output format
+	#[clap(long, default_value = "table")]
+	format: Format,
+
+	/// write the output to this path instead of stdout
+	#[clap(long, short)]
+	output: Option<PathBuf>,
+
+	/// also write the analysis of every column as a json report to this path
+	#[clap(long, conflicts_with_all = ["model", "diff"])]
+	report: Option<PathBuf>,
+
+	/// add a sparkline histogram and a box plot of each numeric column to the table
+	/// (table format only)
+	#[clap(long, conflicts_with = "mode")]
+	shape: bool,
+
+	/// report the missing values instead: nulls per column and per row,
+	/// and columns that are null together (broken down by --label, table format only)
+	#[clap(long, short)]
+	missing: bool,
+
+	/// string column to split the shapes and missing values by
+	#[clap(long, short, requires = "split")]
+	label: Option<String>,
+
+	/// rotate the table 90 degrees (statistics as rows, columns as columns),
+	/// wrapped to the width of the terminal (table format only)
+	#[clap(long, short = 'R')]
 	rotate: bool,
-	*/
+
+	/// inspect this trained model instead: weights, normalization factors and means,
+	/// with features named after the float columns of the csv file
+	/// (the default csv file is skipped when it does not match the model, table format only)
+	#[clap(long)]
+	model: Option<PathBuf>,
+
+	/// compare the columns of both csv files by name: statistics of each,
+	/// their differences, and columns missing on one side or whose dtype changed
+	/// (table format only)
+	#[clap(long, requires = "other")]
+	diff: bool,
+}
+
+impl Args {
+	/// the other formats only export the summary, so they cannot be combined with a mode or shapes
+	fn check(self) -> Result<Self, clap::Error> {
+		let modes = [
+			("missing", self.missing),
+			("rotate", self.rotate),
+			("model", self.model.is_some()),
+			("diff", self.diff),
+			("shape", self.shape),
+		];
+
+		match modes.iter().find(|(_, set)| *set) {
+			Some((mode, _)) if self.format != Format::Table => Err(Args::command().error(
+				ErrorKind::ArgumentConflict,
+				format!("--{mode} only supports --format table"),
+			)),
+			_ => Ok(self),
+		}
+	}
 }
 
-fn main() -> PolarsResult<()> {
-	let args = Args::parse();
+fn main() -> hmerr::Result<()> {
+	let matches = Args::command().get_matches();
+	let args = Args::from_arg_matches(&matches)
+		.and_then(Args::check)
+		.unwrap_or_else(|e| e.exit());
+
+	let selection = Selection::new(&args.columns, &args.exclude, args.regex)?;
+
+	if let Some(path) = &args.model {
+		let model = Model::read(path)?;
+		let explicit = matches.value_source("path") == Some(ValueSource::CommandLine);
+		let df = model_dataset(&model, &args.path, explicit)?;
+
+		let rendered = inspect::present(&inspect::inspect(&model, df.as_ref()), &args);
+		return output(&args, rendered);
+	}
+
+	if let Some(other) = &args.other {
+		let left = selection.apply(load::load(&args.path)?)?;
+		let right = selection.apply(load::load(other)?)?;
+		let columns = (diff::columns(&left), diff::columns(&right));
+
+		let left = compute::compute(left, None, &args)?;
+		let right = compute::compute(right, None, &args)?;
+
+		let names = (args.path.to_string_lossy(), other.to_string_lossy());
+		let rendered = diff::present(
+			(&columns.0, &columns.1),
+			&left,
+			&right,
+			(&names.0, &names.1),
+			&args,
+		);
+		return output(&args, rendered);
+	}
 
 	let df = load::load(&args.path)?;
 
-	let table = compute::compute(df, &args)?;
-	present::present(table, &args)
+	let labels = match &args.label {
+		Some(label) => Some(df.column(label)?.clone()),
+		None => None,
+	};
+
+	let df = selection.apply(df)?;
+
+	if let Some(report) = &args.report {
+		Report::new(args.path.to_string_lossy(), &df).write(report)?;
+	}
+
+	let rendered = if args.missing {
+		missing::present(&missing::missing(&df, labels.as_ref())?, &args)
+	} else {
+		let summary = compute::compute(df, labels.as_ref(), &args)?;
+
+		match args.format {
+			Format::Table if args.rotate => rotate::present(&summary, &args),
+			Format::Table => present::present(&summary, &args),
+			format => export::export(&summary, format, &args)?,
+		}
+	};
+
+	output(&args, rendered)
+}
+
+/// the dataset naming the features of `model`,
+/// `None` when the default one cannot be loaded
+fn model_dataset(model: &Model, path: &Path, explicit: bool) -> hmerr::Result<Option<DataFrame>> {
+	let df = load::load(path).and_then(|mut df| {
+		// the date features the model was trained with
+		if let Some(date) = &model.date {
+			Expansion::new(&date.column, &date.format).apply(&mut df)?;
+		}
+
+		Ok(df)
+	});
+
+	let df = match df {
+		Ok(df) => df,
+		Err(_) if !explicit => return Ok(None),
+		Err(e) => return Err(e.into()),
+	};
+
+	let floats = df
+		.get_columns()
+		.iter()
+		.filter(|series| series.dtype().is_float())
+		.count();
+	if explicit && floats != model.means.len() {
+		return Err(polars_err!(
+			ShapeMismatch: "the model has {} features but {} has {floats} float columns",
+			model.means.len(),
+			path.display()
+		)
+		.into());
+	}
+
+	Ok(Some(df))
+}
+
+fn output(args: &Args, rendered: String) -> hmerr::Result<()> {
+	match &args.output {
+		Some(path) => std::fs::write(path, rendered)?,
+		None => print!("{rendered}"),
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+		Args::try_parse_from([&["describe"][..], args].concat()).and_then(Args::check)
+	}
+
+	#[test]
+	fn test_modes() {
+		assert!(parse(&["--missing"]).is_ok());
+		assert!(parse(&["--rotate", "--format", "table"]).is_ok());
+		assert!(parse(&["a.csv", "b.csv", "--diff"]).is_ok());
+
+		assert!(parse(&["--missing", "--rotate"]).is_err());
+		assert!(parse(&["--model", "model.csv", "--missing"]).is_err());
+		assert!(parse(&["a.csv", "b.csv", "--diff", "--rotate"]).is_err());
+	}
+
+	#[test]
+	fn test_shape() {
+		assert!(parse(&["--shape", "--format", "table"]).is_ok());
+
+		assert!(parse(&["--shape", "--rotate"]).is_err());
+		assert!(parse(&["--shape", "--missing"]).is_err());
+	}
+
+	#[test]
+	fn test_label() {
+		assert!(parse(&["--shape", "--label", "Hogwarts House"]).is_ok());
+		assert!(parse(&["--missing", "--label", "Hogwarts House"]).is_ok());
+
+		assert!(parse(&["--label", "Hogwarts House"]).is_err());
+		assert!(parse(&["--rotate", "--label", "Hogwarts House"]).is_err());
+		assert!(parse(&["--model", "model.csv", "--label", "Hogwarts House"]).is_err());
+	}
+
+	#[test]
+	fn test_report() {
+		assert!(parse(&["--missing", "--report", "report.json"]).is_ok());
+
+		assert!(parse(&["--model", "model.csv", "--report", "report.json"]).is_err());
+		assert!(parse(&["a.csv", "b.csv", "--diff", "--report", "report.json"]).is_err());
+	}
+
+	#[test]
+	fn test_format() {
+		assert!(parse(&["--format", "csv"]).is_ok());
+
+		for mode in [
+			&["--missing"][..],
+			&["--rotate"],
+			&["--model", "model.csv"],
+			&["a.csv", "b.csv", "--diff"],
+			&["--shape"],
+		] {
+			assert!(parse(&[mode, &["--format", "json"]].concat()).is_err());
+		}
+	}
 }
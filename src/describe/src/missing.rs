@@ -0,0 +1,262 @@
+use float::Float;
+use polars::prelude::*;
+use tabled::builder::Builder;
+
+use crate::{compute::COLUMN, present::section, Args};
+
+const PAIRS_SHOWN: usize = 10;
+
+/// where the nulls of a dataset are
+#[derive(Debug, PartialEq)]
+pub struct Missing {
+	pub rows: usize,
+	pub columns: Vec<ColumnMissing>,
+	/// `per_row[k]` is the number of rows with exactly `k` nulls
+	pub per_row: Vec<usize>,
+	/// pairs of columns null on the same rows, most frequent first
+	pub pairs: Vec<Pair>,
+	/// sorted labels of the breakdown
+	pub labels: Vec<String>,
+	/// number of rows of each label
+	pub label_rows: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ColumnMissing {
+	pub name: String,
+	pub nulls: usize,
+	/// nulls of the column for each label
+	pub by_label: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Pair {
+	pub a: String,
+	pub b: String,
+	pub both: usize,
+	/// rows where both are null over rows where at least one is
+	pub jaccard: Float,
+}
+
+pub fn missing(df: &DataFrame, labels: Option<&Series>) -> PolarsResult<Missing> {
+	let rows = df.height();
+
+	let label_values = match labels {
+		Some(labels) => labels
+			.str()?
+			.into_iter()
+			.map(|label| label.map(str::to_owned))
+			.collect::<Vec<_>>(),
+		None => Vec::new(),
+	};
+	let mut sorted_labels = label_values.iter().flatten().cloned().collect::<Vec<_>>();
+	sorted_labels.sort();
+	sorted_labels.dedup();
+
+	let label_index = label_values
+		.iter()
+		.map(|label| {
+			label
+				.as_ref()
+				.and_then(|label| sorted_labels.binary_search(label).ok())
+		})
+		.collect::<Vec<_>>();
+
+	let mut label_rows = vec![0; sorted_labels.len()];
+	for index in label_index.iter().flatten() {
+		label_rows[*index] += 1;
+	}
+
+	let masks = df
+		.get_columns()
+		.iter()
+		.map(|series| {
+			series
+				.is_null()
+				.into_iter()
+				.map(|null| null.unwrap_or(false))
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+
+	let columns = df
+		.get_columns()
+		.iter()
+		.zip(&masks)
+		.map(|(series, mask)| {
+			let mut by_label = vec![0; sorted_labels.len()];
+			for (null, index) in mask.iter().zip(&label_index) {
+				if let (true, Some(index)) = (null, index) {
+					by_label[*index] += 1;
+				}
+			}
+
+			ColumnMissing {
+				name: series.name().to_string(),
+				nulls: series.null_count(),
+				by_label,
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let mut per_row = vec![0; df.width() + 1];
+	for row in 0..rows {
+		per_row[masks.iter().filter(|mask| mask[row]).count()] += 1;
+	}
+	while per_row.len() > 1 && per_row.last() == Some(&0) {
+		per_row.pop();
+	}
+
+	let mut pairs = Vec::new();
+	for a in 0..masks.len() {
+		for b in a + 1..masks.len() {
+			let both = masks[a]
+				.iter()
+				.zip(&masks[b])
+				.filter(|(a, b)| **a && **b)
+				.count();
+			if both == 0 {
+				continue;
+			}
+
+			let either = columns[a].nulls + columns[b].nulls - both;
+
+			pairs.push(Pair {
+				a: columns[a].name.clone(),
+				b: columns[b].name.clone(),
+				both,
+				jaccard: both as Float / either as Float,
+			});
+		}
+	}
+	pairs.sort_by(|x, y| y.both.cmp(&x.both).then(y.jaccard.total_cmp(&x.jaccard)));
+
+	Ok(Missing {
+		rows,
+		columns,
+		per_row,
+		pairs,
+		labels: sorted_labels,
+		label_rows,
+	})
+}
+
+/// render the missing values report as three tables
+pub fn present(missing: &Missing, args: &Args) -> String {
+	let colored = args.output.is_none();
+
+	let mut out = String::new();
+
+	let mut builder = Builder::default();
+	builder.push_record(
+		[COLUMN, "null", "%"]
+			.into_iter()
+			.map(String::from)
+			.chain(missing.labels.iter().cloned()),
+	);
+	for column in &missing.columns {
+		builder.push_record(
+			[
+				column.name.clone(),
+				column.nulls.to_string(),
+				percent(column.nulls, missing.rows, args),
+			]
+			.into_iter()
+			.chain(
+				column
+					.by_label
+					.iter()
+					.zip(&missing.label_rows)
+					.map(|(nulls, rows)| format!("{nulls} ({})", percent(*nulls, *rows, args))),
+			),
+		);
+	}
+	out += &section("nulls per column", builder.build(), colored);
+
+	let mut builder = Builder::default();
+	builder.push_record(["nulls in row", "rows", "%"]);
+	for (nulls, rows) in missing.per_row.iter().enumerate() {
+		builder.push_record([
+			nulls.to_string(),
+			rows.to_string(),
+			percent(*rows, missing.rows, args),
+		]);
+	}
+	out += &section("nulls per row", builder.build(), colored);
+
+	let mut builder = Builder::default();
+	builder.push_record([COLUMN, COLUMN, "both null", "jaccard"]);
+	for pair in missing.pairs.iter().take(PAIRS_SHOWN) {
+		builder.push_record([
+			pair.a.clone(),
+			pair.b.clone(),
+			pair.both.to_string(),
+			format!("{:.1$}", pair.jaccard, args.round as usize),
+		]);
+	}
+	out += &section("columns null together", builder.build(), colored);
+
+	out
+}
+
+fn percent(part: usize, total: usize, args: &Args) -> String {
+	if total == 0 {
+		return String::new();
+	}
+
+	format!(
+		"{:.1$}%",
+		part as Float / total as Float * 100.0,
+		args.round as usize
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_missing() {
+		let df = DataFrame::new(vec![
+			Series::new("a".into(), &[Some(1.0), None, None, Some(4.0)]),
+			Series::new("b".into(), &[Some(1.0), None, Some(3.0), None]),
+			Series::new("c".into(), &[Some(1), Some(2), Some(3), Some(4)]),
+		])
+		.unwrap();
+		let labels = Series::new("l".into(), &["y", "x", "x", "y"]);
+
+		let missing = missing(&df, Some(&labels)).unwrap();
+
+		assert_eq!(missing.rows, 4);
+		assert_eq!(missing.labels, ["x", "y"]);
+		assert_eq!(missing.label_rows, [2, 2]);
+
+		assert_eq!(missing.columns[0].nulls, 2);
+		assert_eq!(missing.columns[0].by_label, [2, 0]);
+		assert_eq!(missing.columns[1].by_label, [1, 1]);
+		assert_eq!(missing.columns[2].nulls, 0);
+
+		assert_eq!(missing.per_row, [1, 2, 1]);
+
+		assert_eq!(
+			missing.pairs,
+			[Pair {
+				a: String::from("a"),
+				b: String::from("b"),
+				both: 1,
+				jaccard: 1.0 / 3.0,
+			}]
+		);
+	}
+
+	#[test]
+	fn test_missing_nothing() {
+		let df = DataFrame::new(vec![Series::new("a".into(), &[1.0, 2.0])]).unwrap();
+
+		let missing = missing(&df, None).unwrap();
+
+		assert_eq!(missing.per_row, [2]);
+		assert!(missing.pairs.is_empty());
+		assert!(missing.labels.is_empty());
+	}
+}
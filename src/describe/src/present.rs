@@ -1,5 +1,8 @@
+use std::ops::Range;
+
 use polars::prelude::*;
 use tabled::{
+	builder::Builder,
 	settings::{
 		object::{Cell, Columns, Rows},
 		style::BorderColor,
@@ -9,29 +12,191 @@ use tabled::{
 	Table,
 };
 
-use crate::Args;
+use crate::{
+	compute::{Summary, COLUMN, TYPE},
+	shape::SHAPE,
+	terminal::{self, display_width},
+	Args,
+};
+
+pub const EMPTY_CELL: &str = "N/A";
+
+const MIN_NAME_WIDTH: usize = 6;
+const CLASS_PREFIX: &str = "  ";
+
+/// render the summary as a table, colored unless it is written to a file,
+/// names are shortened then statistics are wrapped when it is too wide for the terminal
+pub fn present(summary: &Summary, args: &Args) -> String {
+	let available = terminal::available(args);
+
+	let mut headers = summary.headers().collect::<Vec<_>>();
+	if !summary.shapes.is_empty() {
+		headers.push(SHAPE.to_string());
+	}
+
+	let lines = lines(summary, args, headers.len());
+
+	let widths = headers
+		.iter()
+		.enumerate()
+		.map(|(i, header)| {
+			lines
+				.iter()
+				.map(|line| display_width(&line.cells[i]))
+				.chain([display_width(header), display_width(EMPTY_CELL)])
+				.max()
+				.unwrap_or_default()
+				+ 2
+		})
+		.collect::<Vec<_>>();
+
+	let type_width = lines
+		.iter()
+		.map(|line| display_width(&line.dtype()))
+		.chain([display_width(TYPE)])
+		.max()
+		.unwrap_or_default()
+		+ 2;
 
-const EMPTY_CELL: &str = "N/A";
+	let rest = type_width + 1 + widths.iter().map(|width| width + 1).sum::<usize>();
+	let name_width = name_width(&lines, args, available, rest);
 
-pub fn present(table: (Table, Vec<DataType>), _args: &Args) -> PolarsResult<()> {
-	let (mut table, types) = table;
+	let groups = match available {
+		Some(available) if !widths.is_empty() => {
+			terminal::wrap(name_width + 2 + 1 + type_width, &widths, available)
+		}
+		_ => vec![0..widths.len()],
+	};
+
+	groups
+		.into_iter()
+		.map(|group| {
+			let mut table = build(&headers, &lines, name_width, group);
+
+			style(&mut table, &lines);
+			if args.output.is_none() {
+				colorize(&mut table, &lines);
+			}
+
+			format!("{table}\n")
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// a row of the table: a column of the dataset,
+/// or one of its labels when its shape is split by label
+struct Line<'s> {
+	name: String,
+	dtype: Option<&'s DataType>,
+	class: Option<usize>,
+	cells: Vec<String>,
+}
+
+impl Line<'_> {
+	fn dtype(&self) -> String {
+		self.dtype.map(DataType::to_string).unwrap_or_default()
+	}
+}
 
+fn lines<'s>(summary: &'s Summary, args: &Args, len: usize) -> Vec<Line<'s>> {
+	let mut lines = Vec::with_capacity(summary.rows.len());
+
+	for (i, row) in summary.rows.iter().enumerate() {
+		let shape = summary.shapes.get(i).and_then(Option::as_ref);
+
+		let mut cells = summary.cells(row, args).collect::<Vec<_>>();
+		if !summary.shapes.is_empty() {
+			cells.push(shape.map(|shape| shape.all.clone()).unwrap_or_default());
+		}
+
+		lines.push(Line {
+			name: row.name.clone(),
+			dtype: Some(&row.dtype),
+			class: None,
+			cells,
+		});
+
+		let Some(shape) = shape else {
+			continue;
+		};
+
+		for (class, (label, drawing)) in summary.labels.iter().zip(&shape.classes).enumerate() {
+			let mut cells = vec![String::new(); len];
+			cells[len - 1] = drawing.clone();
+
+			lines.push(Line {
+				name: format!("{CLASS_PREFIX}{label}"),
+				dtype: None,
+				class: Some(class),
+				cells,
+			});
+		}
+	}
+
+	lines
+}
+
+/// width of the name column: `--name-width`, or the longest name
+/// shortened just enough for the table to fit in the terminal
+fn name_width(lines: &[Line], args: &Args, available: Option<usize>, rest: usize) -> usize {
+	if let Some(width) = args.name_width {
+		return width;
+	}
+
+	let longest = lines
+		.iter()
+		.map(|line| display_width(&line.name))
+		.chain([display_width(COLUMN)])
+		.max()
+		.unwrap_or_default();
+
+	let Some(available) = available else {
+		return longest;
+	};
+
+	let total = 1 + longest + 2 + 1 + rest;
+	if total <= available {
+		return longest;
+	}
+
+	longest
+		.saturating_sub(total - available)
+		.max(MIN_NAME_WIDTH)
+		.min(longest)
+}
+
+fn build(headers: &[String], lines: &[Line], name_width: usize, group: Range<usize>) -> Table {
+	let mut builder = Builder::default();
+
+	builder.push_record(
+		[COLUMN, TYPE]
+			.into_iter()
+			.map(String::from)
+			.chain(headers[group.clone()].iter().cloned()),
+	);
+
+	for line in lines {
+		builder.push_record(
+			[terminal::truncate(&line.name, name_width), line.dtype()]
+				.into_iter()
+				.chain(line.cells[group.clone()].iter().cloned()),
+		);
+	}
+
+	builder.build()
+}
+
+fn style(table: &mut Table, lines: &[Line]) {
 	table
 		.with(Style::rounded())
-		.with(BorderColor::filled(Color::new("\u{1b}[2;35m", "\u{1b}[0m")))
 		.modify(Rows::new(1..), Alignment::right());
 
-	for (i, data_type) in types.iter().enumerate() {
-		if !data_type.is_numeric() {
-			let target = Rows::single(1 + i);
-
+	for (i, line) in lines.iter().enumerate() {
+		if line.dtype.is_some_and(|dtype| !dtype.is_numeric()) {
 			table
-				.with(Colorization::exact(
-					[Color::new("\u{1b}[2;3m", "\u{1b}[0m")],
-					target,
-				))
 				.modify(
-					target,
+					Rows::single(1 + i),
 					Format::content(|s| {
 						if s.is_empty() {
 							EMPTY_CELL.to_string()
@@ -40,35 +205,94 @@ pub fn present(table: (Table, Vec<DataType>), _args: &Args) -> PolarsResult<()>
 						}
 					}),
 				)
-				.modify(target, Alignment::center());
+				.modify(Rows::single(1 + i), Alignment::center());
 		}
 
-		let target = Cell::from((1 + i, 1));
-
-		table.modify(target, Alignment::center());
-
-		table.with(Colorization::exact([type_color(data_type)], target));
+		table.modify(Cell::from((1 + i, 1)), Alignment::center());
 	}
 
 	table
 		.modify(Columns::first(), Alignment::left())
 		.modify(Columns::single(1), Alignment::center())
-		.modify(Rows::first(), Alignment::center())
-		.with(Colorization::exact(
-			[Color::new("\u{1b}[3m", "\u{1b}[0m")],
-			Columns::first(),
-		))
+		.modify(Rows::first(), Alignment::center());
+}
+
+fn colorize(table: &mut Table, lines: &[Line]) {
+	table.with(BorderColor::filled(Color::new("\u{1b}[2;35m", "\u{1b}[0m")));
+
+	for (i, line) in lines.iter().enumerate() {
+		let Some(dtype) = line.dtype else {
+			continue;
+		};
+
+		if !dtype.is_numeric() {
+			table.with(Colorization::exact(
+				[Color::new("\u{1b}[2;3m", "\u{1b}[0m")],
+				Rows::single(1 + i),
+			));
+		}
+
+		table.with(Colorization::exact(
+			[type_color(dtype)],
+			Cell::from((1 + i, 1)),
+		));
+	}
+
+	table.with(Colorization::exact(
+		[Color::new("\u{1b}[3m", "\u{1b}[0m")],
+		Columns::first(),
+	));
+
+	for (i, line) in lines.iter().enumerate() {
+		if let Some(class) = line.class {
+			table.with(Colorization::exact(
+				[class_color(class)],
+				Rows::single(1 + i),
+			));
+		}
+	}
+
+	table.with(Colorization::exact(
+		[Color::new("\u{1b}[1m", "\u{1b}[0m")],
+		Rows::first(),
+	));
+}
+
+fn class_color(class: usize) -> Color {
+	const COLORS: [&str; 6] = [
+		"\u{1b}[31m",
+		"\u{1b}[32m",
+		"\u{1b}[33m",
+		"\u{1b}[34m",
+		"\u{1b}[35m",
+		"\u{1b}[36m",
+	];
+
+	Color::new(COLORS[class % COLORS.len()], "\u{1b}[0m")
+}
+
+/// a titled table with the default style, used by the other reports
+pub fn section(title: &str, mut table: Table, colored: bool) -> String {
+	table
+		.with(Style::rounded())
+		.modify(Rows::new(1..), Alignment::right())
+		.modify(Rows::first(), Alignment::center());
+
+	if !colored {
+		return format!("{title}\n{table}\n\n");
+	}
+
+	table
+		.with(BorderColor::filled(Color::new("\u{1b}[2;35m", "\u{1b}[0m")))
 		.with(Colorization::exact(
 			[Color::new("\u{1b}[1m", "\u{1b}[0m")],
 			Rows::first(),
 		));
 
-	println!("{table}");
-
-	Ok(())
+	format!("\u{1b}[1;3m{title}\u{1b}[0m\n{table}\n\n")
 }
 
-fn type_color(data_type: &DataType) -> Color {
+pub fn type_color(data_type: &DataType) -> Color {
 	if data_type.is_float() {
 		Color::new("\u{1b}[1;36m", "\u{1b}[0m")
 	} else if data_type.is_integer() {
@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use tabled::{
+	builder::Builder,
+	settings::{
+		object::{Cell, Columns, Rows},
+		style::BorderColor,
+		themes::Colorization,
+		Alignment, Color, Format, Style,
+	},
+	Table,
+};
+
+use crate::{
+	compute::{Row, Summary, TYPE},
+	present::{type_color, EMPTY_CELL},
+	terminal::{self, display_width},
+	Args,
+};
+
+/// render the summary with the statistics as rows and the columns as columns,
+/// split in several tables when it does not fit in the terminal
+pub fn present(summary: &Summary, args: &Args) -> String {
+	let available = terminal::available(args);
+
+	let first = [TYPE.to_string()]
+		.into_iter()
+		.chain(summary.headers())
+		.map(|s| display_width(&s))
+		.max()
+		.unwrap_or_default()
+		+ 2;
+
+	// without --name-width, only the names too long to fit alone next to the statistics are cut
+	let name_width = args.name_width.unwrap_or_else(|| match available {
+		Some(available) => available.saturating_sub(1 + first + 1 + 2 + 1),
+		None => usize::MAX,
+	});
+
+	let columns = summary
+		.rows
+		.iter()
+		.map(|row| column(summary, row, name_width, args))
+		.collect::<Vec<_>>();
+
+	let widths = columns
+		.iter()
+		.map(|cells| {
+			cells
+				.iter()
+				.map(|s| display_width(s))
+				.chain([display_width(EMPTY_CELL)])
+				.max()
+				.unwrap_or_default()
+				+ 2
+		})
+		.collect::<Vec<_>>();
+
+	let groups = match available {
+		Some(available) => terminal::wrap(first, &widths, available),
+		None => vec![0..columns.len()],
+	};
+
+	groups
+		.into_iter()
+		.map(|group| {
+			let mut table = build(summary, &columns[group.clone()]);
+
+			style(&mut table, summary, group.clone());
+			if args.output.is_none() {
+				colorize(&mut table, summary, group);
+			}
+
+			format!("{table}\n")
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+fn column(summary: &Summary, row: &Row, name_width: usize, args: &Args) -> Vec<String> {
+	[
+		terminal::truncate(&row.name, name_width),
+		row.dtype.to_string(),
+	]
+	.into_iter()
+	.chain(summary.cells(row, args))
+	.collect()
+}
+
+fn build(summary: &Summary, columns: &[Vec<String>]) -> Table {
+	let mut builder = Builder::default();
+
+	let labels = ["", TYPE]
+		.into_iter()
+		.map(String::from)
+		.chain(summary.headers());
+
+	for (i, label) in labels.enumerate() {
+		builder.push_record(
+			[label]
+				.into_iter()
+				.chain(columns.iter().map(|cells| cells[i].clone())),
+		);
+	}
+
+	builder.build()
+}
+
+fn style(table: &mut Table, summary: &Summary, group: Range<usize>) {
+	table
+		.with(Style::rounded())
+		.modify(Columns::new(1..), Alignment::right());
+
+	for (i, row) in summary.rows[group].iter().enumerate() {
+		if !row.dtype.is_numeric() {
+			table
+				.modify(
+					Columns::single(1 + i),
+					Format::content(|s| {
+						if s.is_empty() {
+							EMPTY_CELL.to_string()
+						} else {
+							s.to_string()
+						}
+					}),
+				)
+				.modify(Columns::single(1 + i), Alignment::center());
+		}
+	}
+
+	table
+		.modify(Columns::first(), Alignment::left())
+		.modify(Rows::first(), Alignment::center())
+		.modify(Rows::single(1), Alignment::center());
+}
+
+fn colorize(table: &mut Table, summary: &Summary, group: Range<usize>) {
+	table.with(BorderColor::filled(Color::new("\u{1b}[2;35m", "\u{1b}[0m")));
+
+	for (i, row) in summary.rows[group].iter().enumerate() {
+		if !row.dtype.is_numeric() {
+			table.with(Colorization::exact(
+				[Color::new("\u{1b}[2;3m", "\u{1b}[0m")],
+				Columns::single(1 + i),
+			));
+		}
+
+		table.with(Colorization::exact(
+			[type_color(&row.dtype)],
+			Cell::from((1, 1 + i)),
+		));
+	}
+
+	table
+		.with(Colorization::exact(
+			[Color::new("\u{1b}[3m", "\u{1b}[0m")],
+			Rows::first(),
+		))
+		.with(Colorization::exact(
+			[Color::new("\u{1b}[1m", "\u{1b}[0m")],
+			Columns::first(),
+		));
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use analyze::{
+	histogram::{histogram, sparkline, BinRule, Histogram},
+	Analysis,
+};
+use float::Float;
+use polars::prelude::*;
+
+pub const SHAPE: &str = "shape";
+
+const BINS: usize = 12;
+const BOX_WIDTH: usize = 15;
+
+/// text histogram and box plot of a numeric column,
+/// and of the same column for each label when there are labels
+pub struct Shape {
+	pub all: String,
+	pub classes: Vec<String>,
+}
+
+/// `labels` is the sorted list of every label, the classes of each shape follow its order
+pub fn shapes(
+	df: &DataFrame,
+	names: &[String],
+	labels: Option<&Series>,
+) -> PolarsResult<(Vec<String>, Vec<Option<Shape>>)> {
+	let label_values = match labels {
+		Some(labels) => labels
+			.str()?
+			.into_iter()
+			.map(|label| label.map(str::to_owned))
+			.collect::<Vec<_>>(),
+		None => Vec::new(),
+	};
+
+	let mut sorted_labels = label_values.iter().flatten().cloned().collect::<Vec<_>>();
+	sorted_labels.sort();
+	sorted_labels.dedup();
+
+	let shapes = names
+		.iter()
+		.map(|name| {
+			let series = df.column(name)?;
+			Ok(shape(series, &label_values, &sorted_labels))
+		})
+		.collect::<PolarsResult<Vec<_>>>()?;
+
+	Ok((sorted_labels, shapes))
+}
+
+fn shape(series: &Series, label_values: &[Option<String>], labels: &[String]) -> Option<Shape> {
+	let all = histogram(series, BinRule::Fixed(BINS))?;
+	let analysis = Analysis::from(series);
+	let range = analysis.min.zip(analysis.max)?;
+
+	let mut grouped: BTreeMap<&str, Vec<Float>> = BTreeMap::new();
+	for (value, label) in analyze::values(series).into_iter().zip(label_values) {
+		if let (Some(value), Some(label)) = (value, label) {
+			grouped.entry(label.as_str()).or_default().push(value);
+		}
+	}
+
+	let classes = labels
+		.iter()
+		.map(|label| {
+			let values = grouped.remove(label.as_str()).unwrap_or_default();
+			let analysis = Analysis::from(Series::new(label.as_str().into(), &values));
+			let histogram = Histogram::with_edges(values, all.edges.clone());
+
+			draw(&histogram, &analysis, range)
+		})
+		.collect();
+
+	Some(Shape {
+		all: draw(&all, &analysis, range),
+		classes,
+	})
+}
+
+fn draw(histogram: &Histogram, analysis: &Analysis, range: (Float, Float)) -> String {
+	format!(
+		"{} {}",
+		sparkline(&histogram.counts),
+		box_plot(analysis, range, BOX_WIDTH)
+	)
+}
+
+/// `├──██┃██──┤` from min to max through q1, median and q3,
+/// placed on `width` characters spanning `range`
+pub fn box_plot(analysis: &Analysis, range: (Float, Float), width: usize) -> String {
+	let (Some(min), Some(q1), Some(median), Some(q3), Some(max)) = (
+		analysis.min,
+		analysis.q1,
+		analysis.median,
+		analysis.q3,
+		analysis.max,
+	) else {
+		return " ".repeat(width);
+	};
+
+	let (low, high) = range;
+	let position = |x: Float| {
+		if high <= low || width < 2 {
+			return 0;
+		}
+
+		(((x - low) / (high - low)) * (width - 1) as Float).round() as usize
+	};
+
+	let (min, q1, median, q3, max) = (
+		position(min),
+		position(q1),
+		position(median),
+		position(q3),
+		position(max),
+	);
+
+	(0..width)
+		.map(|i| match i {
+			_ if i < min || i > max => ' ',
+			_ if i == median => '┃',
+			_ if i == min => '├',
+			_ if i == max => '┤',
+			_ if (q1..=q3).contains(&i) => '█',
+			_ => '─',
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_box_plot() {
+		let s = Series::new("a".into(), &[0.0, 2.0, 5.0, 8.0, 10.0]);
+		let analysis = Analysis::from(&s);
+
+		assert_eq!(box_plot(&analysis, (0.0, 10.0), 11), "├─███┃███─┤");
+		assert_eq!(box_plot(&analysis, (-10.0, 10.0), 11), "     ├██┃█┤");
+	}
+
+	#[test]
+	fn test_box_plot_empty() {
+		let s = Series::new("a".into(), &["a"]);
+		let analysis = Analysis::from(&s);
+
+		assert_eq!(box_plot(&analysis, (0.0, 1.0), 4), "    ");
+	}
+}
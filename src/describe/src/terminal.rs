@@ -0,0 +1,128 @@
+use std::ops::Range;
+
+use terminal_size::{terminal_size, Width};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::Args;
+
+const ELLIPSIS: char = '…';
+
+/// width of the terminal, `None` when stdout is not a terminal
+pub fn width() -> Option<usize> {
+	terminal_size().map(|(Width(width), _)| width as usize)
+}
+
+/// width the output has to fit in, `None` when it is written to a file
+pub fn available(args: &Args) -> Option<usize> {
+	if args.output.is_some() {
+		return None;
+	}
+
+	width()
+}
+
+/// number of terminal columns `s` takes
+pub fn display_width(s: &str) -> usize {
+	UnicodeWidthStr::width(s)
+}
+
+/// cut `s` to at most `width` terminal columns without splitting a character,
+/// ending with `…` when something was removed and there is room for it
+pub fn truncate(s: &str, width: usize) -> String {
+	if display_width(s) <= width {
+		return s.to_owned();
+	}
+
+	if width == 0 {
+		return String::new();
+	}
+
+	let mut truncated = String::new();
+	let mut used = 0;
+
+	for grapheme in s.graphemes(true) {
+		let grapheme_width = display_width(grapheme);
+		if used + grapheme_width + 1 > width {
+			break;
+		}
+
+		truncated.push_str(grapheme);
+		used += grapheme_width;
+	}
+
+	truncated.push(ELLIPSIS);
+	truncated
+}
+
+/// split the columns of `widths` in consecutive groups that fit in `available`
+/// when each group is rendered next to a first column of width `fixed`,
+/// widths are the content of a cell with its padding, borders are counted here
+pub fn wrap(fixed: usize, widths: &[usize], available: usize) -> Vec<Range<usize>> {
+	let mut groups = Vec::new();
+
+	let mut start = 0;
+	let mut used = 1 + fixed + 1;
+
+	for (i, width) in widths.iter().enumerate() {
+		if i > start && used + width + 1 > available {
+			groups.push(start..i);
+			start = i;
+			used = 1 + fixed + 1;
+		}
+
+		used += width + 1;
+	}
+
+	if start < widths.len() {
+		groups.push(start..widths.len());
+	}
+
+	groups
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_truncate() {
+		assert_eq!(truncate("Potions", 10), "Potions");
+		assert_eq!(truncate("Potions", 7), "Potions");
+		assert_eq!(truncate("Potions", 6), "Potio…");
+		assert_eq!(truncate("Potions", 1), "…");
+		assert_eq!(truncate("Potions", 0), "");
+		assert_eq!(truncate("", 0), "");
+	}
+
+	#[test]
+	fn test_truncate_unicode() {
+		assert_eq!(truncate("Défense contre", 5), "Défe…");
+		// wide characters take 2 columns
+		assert_eq!(truncate("魔法薬学", 5), "魔法…");
+		// e followed by a combining acute accent is a single grapheme
+		assert_eq!(truncate("e\u{301}e\u{301}e\u{301}", 2), "e\u{301}…");
+	}
+
+	#[test]
+	fn test_wrap_fit() {
+		assert_eq!(wrap(5, &[4, 4, 4], 80), vec![0..3]);
+	}
+
+	#[test]
+	fn test_wrap_split() {
+		// | fixed | a | b | is 1 + 5 + 1 + 4 + 1 + 4 + 1 = 17
+		assert_eq!(wrap(5, &[4, 4, 4, 4], 17), vec![0..2, 2..4]);
+		assert_eq!(wrap(5, &[4, 4, 4, 4], 16), vec![0..1, 1..2, 2..3, 3..4]);
+	}
+
+	#[test]
+	fn test_wrap_too_wide() {
+		assert_eq!(wrap(5, &[40, 4], 20), vec![0..1, 1..2]);
+	}
+
+	#[test]
+	fn test_wrap_empty() {
+		assert!(wrap(5, &[], 20).is_empty());
+	}
+}
@@ -1,5 +1,6 @@
 use hmerr::{ioe, se};
-use std::{collections::HashMap, path::Path};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, io::Write, path::Path};
 
 use float::Float;
 
@@ -9,8 +10,27 @@ pub struct Model {
 	pub weights: HashMap<String, Vec<Float>>,
 	pub normalization_factors: Vec<(Float, Float)>,
 	pub means: Vec<Float>,
+	/// date column expanded into features before training
+	pub date: Option<DateColumn>,
 }
 
+/// a date column and the chrono format of its dates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateColumn {
+	pub column: String,
+	pub format: String,
+}
+
+/// how the dataset was transformed before training,
+/// written as json on a comment line before the header
+#[derive(Default, Serialize, Deserialize)]
+struct Metadata {
+	#[serde(default)]
+	date: Option<DateColumn>,
+}
+
+const COMMENT: u8 = b'#';
+
 const MEANS_AMOUNT: usize = 1;
 const FACTOR_AMOUNT: usize = 2;
 
@@ -18,7 +38,22 @@ const EXTRA_COL_AMOUNT: usize = MEANS_AMOUNT + FACTOR_AMOUNT;
 
 impl Model {
 	pub fn write(&self, path: &Path) -> std::io::Result<()> {
-		let mut wtr = csv::Writer::from_path(path)?;
+		let mut file = std::fs::File::create(path)?;
+
+		// models without metadata keep the plain csv format
+		if self.date.is_some() {
+			let metadata = Metadata {
+				date: self.date.clone(),
+			};
+			writeln!(
+				file,
+				"{} {}",
+				COMMENT as char,
+				serde_json::to_string(&metadata)?
+			)?;
+		}
+
+		let mut wtr = csv::Writer::from_writer(file);
 
 		let record_len = EXTRA_COL_AMOUNT + self.weights.len();
 
@@ -57,9 +92,15 @@ impl Model {
 	}
 
 	pub fn read(path: &Path) -> hmerr::Result<Self> {
-		let mut rdr = csv::Reader::from_path(path).map_err(|e| ioe!(path.to_string_lossy(), e))?;
-
-		let mut model = Model::default();
+		let mut rdr = csv::ReaderBuilder::new()
+			.comment(Some(COMMENT))
+			.from_path(path)
+			.map_err(|e| ioe!(path.to_string_lossy(), e))?;
+
+		let mut model = Model {
+			date: Self::read_metadata(path)?.date,
+			..Default::default()
+		};
 
 		let mut header = rdr
 			.headers()
@@ -123,6 +164,27 @@ impl Model {
 
 		Ok(model)
 	}
+
+	fn read_metadata(path: &Path) -> hmerr::Result<Metadata> {
+		let content = std::fs::read_to_string(path).map_err(|e| ioe!(path.to_string_lossy(), e))?;
+
+		let Some(line) = content
+			.lines()
+			.next()
+			.and_then(|line| line.strip_prefix(COMMENT as char))
+		else {
+			return Ok(Metadata::default());
+		};
+
+		serde_json::from_str(line).map_err(|e| {
+			se!(
+				format!("could not \x1b[1;31mparse\x1b[0m \x1b[1;33mmodel ({path})\x1b[0m", path=path.to_string_lossy()),
+				"metadata",
+				line,
+				s:e,
+			)
+		})
+	}
 }
 
 #[cfg(test)]
@@ -141,6 +203,7 @@ mod test {
 			.collect(),
 			normalization_factors: vec![(1.0, 2.0), (3.0, 4.0)],
 			means: vec![0.5, 1.5],
+			date: None,
 		};
 
 		let path = Path::new("/tmp/cargo_test_dslr_model.csv");
@@ -156,5 +219,31 @@ mod test {
 			read_model.normalization_factors
 		);
 		assert_eq!(model.means, read_model.means);
+		assert_eq!(read_model.date, None);
+	}
+
+	#[test]
+	fn test_model_date() {
+		let date = super::DateColumn {
+			column: "Birthday".to_string(),
+			format: "%Y-%m-%d".to_string(),
+		};
+		let model = super::Model {
+			label_name: "house".to_string(),
+			weights: vec![("a".to_string(), vec![1.0])].into_iter().collect(),
+			normalization_factors: vec![(0.0, 1.0)],
+			means: vec![2.0],
+			date: Some(date.clone()),
+		};
+
+		let path = Path::new("/tmp/cargo_test_dslr_model_date.csv");
+
+		model.write(path).unwrap();
+
+		let read_model = super::Model::read(path).unwrap();
+
+		assert_eq!(read_model.date, Some(date));
+		assert_eq!(read_model.label_name, "house");
+		assert_eq!(read_model.means, [2.0]);
 	}
 }
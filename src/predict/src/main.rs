@@ -3,6 +3,7 @@ mod predict;
 use std::path::PathBuf;
 
 use clap::Parser;
+use date::Expansion;
 use model::Model;
 
 #[derive(Parser)]
@@ -24,10 +25,15 @@ pub struct Args {
 fn main() -> hmerr::Result<()> {
 	let args = Args::parse();
 
-	let df = load::load(&args.path)?;
-
 	let model = Model::read(&args.model)?;
 
+	let mut df = load::load(&args.path)?;
+
+	// the date features the model was trained with
+	if let Some(date) = &model.date {
+		Expansion::new(&date.column, &date.format).apply(&mut df)?;
+	}
+
 	predict::predict(&args, df, &model)?;
 
 	Ok(())
@@ -9,12 +9,21 @@ use crate::Args;
 const INDEX_COLUMN: &str = "Index";
 
 pub fn predict(args: &Args, df: DataFrame, model: &Model) -> hmerr::Result<()> {
+	let capacity = get_capacity(&df);
+
+	if capacity != model.means.len() {
+		return Err(polars_err!(
+			ShapeMismatch: "the model has {} features but {} has {capacity} float columns",
+			model.means.len(),
+			args.path.display()
+		)
+		.into());
+	}
+
 	let mut wtr = csv::Writer::from_path(&args.output)?;
 
 	wtr.write_record([INDEX_COLUMN, &model.label_name])?;
 
-	let capacity = get_capacity(&df);
-
 	let mut iters = df.iter().map(|s| s.iter()).collect::<Vec<_>>();
 
 	for idx in 0..df.height() {
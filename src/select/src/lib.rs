@@ -0,0 +1,189 @@
+use polars::prelude::*;
+use regex::Regex;
+
+/// columns to keep from a DataFrame
+///
+/// a column is kept if it matches one of the `include` patterns
+/// (or if there is none) and none of the `exclude` patterns,
+/// patterns are globs (`*` and `?`) or regexes and must match the whole name
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+	include: Vec<Pattern>,
+	exclude: Vec<Pattern>,
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+	source: String,
+	regex: Regex,
+}
+
+impl Pattern {
+	fn new(source: &str, regex: bool) -> PolarsResult<Self> {
+		let expr = if regex {
+			format!("^(?:{source})$")
+		} else {
+			glob_to_regex(source)
+		};
+
+		let regex = Regex::new(&expr).map_err(|e| {
+			PolarsError::ComputeError(format!("invalid column pattern {source}: {e}").into())
+		})?;
+
+		Ok(Self {
+			source: source.to_owned(),
+			regex,
+		})
+	}
+
+	fn matches(&self, name: &str) -> bool {
+		self.regex.is_match(name)
+	}
+}
+
+fn glob_to_regex(glob: &str) -> String {
+	let mut expr = String::from("^");
+
+	for c in glob.chars() {
+		match c {
+			'*' => expr.push_str(".*"),
+			'?' => expr.push('.'),
+			_ => expr.push_str(&regex::escape(&c.to_string())),
+		}
+	}
+
+	expr.push('$');
+	expr
+}
+
+impl Selection {
+	pub fn new<S: AsRef<str>>(include: &[S], exclude: &[S], regex: bool) -> PolarsResult<Self> {
+		let parse = |patterns: &[S]| {
+			patterns
+				.iter()
+				.map(|p| Pattern::new(p.as_ref(), regex))
+				.collect::<PolarsResult<Vec<_>>>()
+		};
+
+		Ok(Self {
+			include: parse(include)?,
+			exclude: parse(exclude)?,
+		})
+	}
+
+	pub fn matches(&self, name: &str) -> bool {
+		(self.include.is_empty() || self.include.iter().any(|p| p.matches(name)))
+			&& !self.exclude.iter().any(|p| p.matches(name))
+	}
+
+	/// names of the selected columns, in the order of `names`,
+	/// every `include` pattern must match at least one column
+	pub fn filter<'n>(
+		&self,
+		names: impl IntoIterator<Item = &'n str>,
+	) -> PolarsResult<Vec<String>> {
+		let names = names.into_iter().collect::<Vec<_>>();
+
+		if let Some(unused) = self
+			.include
+			.iter()
+			.find(|p| !names.iter().any(|name| p.matches(name)))
+		{
+			return Err(PolarsError::ColumnNotFound(
+				format!("no column matches {}", unused.source).into(),
+			));
+		}
+
+		Ok(names
+			.into_iter()
+			.filter(|name| self.matches(name))
+			.map(str::to_owned)
+			.collect())
+	}
+
+	/// keep only the selected columns of `df`
+	pub fn apply(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+		if self.include.is_empty() && self.exclude.is_empty() {
+			return Ok(df);
+		}
+
+		let names = self.filter(df.get_columns().iter().map(|s| s.name().as_str()))?;
+
+		df.select(names)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn df() -> DataFrame {
+		DataFrame::new(vec![
+			Series::new("Index".into(), &[0, 1]),
+			Series::new("Defense Against the Dark Arts".into(), &[1.0, 2.0]),
+			Series::new("Divination".into(), &[3.0, 4.0]),
+			Series::new("Potions".into(), &[5.0, 6.0]),
+		])
+		.unwrap()
+	}
+
+	fn names(df: &DataFrame) -> Vec<String> {
+		df.get_columns()
+			.iter()
+			.map(|s| s.name().to_string())
+			.collect()
+	}
+
+	#[test]
+	fn test_select_nothing() {
+		let selection = Selection::new::<&str>(&[], &[], false).unwrap();
+
+		assert_eq!(names(&selection.apply(df()).unwrap()), names(&df()));
+	}
+
+	#[test]
+	fn test_select_glob() {
+		let selection = Selection::new(&["Potions", "D*"], &[], false).unwrap();
+
+		assert_eq!(
+			names(&selection.apply(df()).unwrap()),
+			["Defense Against the Dark Arts", "Divination", "Potions"]
+		);
+	}
+
+	#[test]
+	fn test_select_exclude() {
+		let selection = Selection::new(&[], &["Index", "?ivination"], false).unwrap();
+
+		assert_eq!(
+			names(&selection.apply(df()).unwrap()),
+			["Defense Against the Dark Arts", "Potions"]
+		);
+	}
+
+	#[test]
+	fn test_select_regex() {
+		let selection = Selection::new(&["D.*"], &[".*Arts"], true).unwrap();
+
+		assert_eq!(names(&selection.apply(df()).unwrap()), ["Divination"]);
+
+		let selection = Selection::new(&["D.*"], &[], false).unwrap();
+
+		assert!(selection.apply(df()).is_err());
+	}
+
+	#[test]
+	fn test_select_glob_is_literal() {
+		assert!(!Selection::new(&["Po.ions"], &[], false)
+			.unwrap()
+			.matches("Potions"));
+		assert!(Selection::new(&["Po.ions"], &[], true)
+			.unwrap()
+			.matches("Potions"));
+	}
+
+	#[test]
+	fn test_select_invalid_regex() {
+		assert!(Selection::new(&["("], &[], true).is_err());
+	}
+}
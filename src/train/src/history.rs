@@ -0,0 +1,146 @@
+use std::{fs::File, io, path::Path};
+
+use itertools::Itertools;
+use model::Model;
+
+use crate::{
+	loss,
+	prepare::{GroupedDatasets, Set},
+};
+
+const HEADER: [&str; 5] = ["iteration", "class", "set", "loss", "accuracy"];
+
+/// loss and accuracy of the classifier of every class at each iteration,
+/// written as `iteration,class,set,loss,accuracy` csv rows
+pub struct History {
+	wtr: csv::Writer<File>,
+}
+
+impl History {
+	pub fn create(path: &Path) -> io::Result<Self> {
+		let mut wtr = csv::Writer::from_path(path)?;
+
+		wtr.write_record(HEADER)?;
+
+		Ok(Self { wtr })
+	}
+
+	/// both are measured over the rows of every class, an empty set is skipped
+	pub fn record(
+		&mut self,
+		iteration: usize,
+		grouped_datasets: &GroupedDatasets,
+		model: &Model,
+	) -> io::Result<()> {
+		for (label, datasets) in grouped_datasets.iter().sorted_by_key(|(label, _)| *label) {
+			let thetas = &model.weights[label];
+
+			for set in [Set::Training, Set::Testing] {
+				if grouped_datasets
+					.values()
+					.all(|datasets| datasets.rows(set).is_empty())
+				{
+					continue;
+				}
+
+				let loss = loss::log_loss(label, thetas, grouped_datasets, set);
+				let accuracy = loss::accuracy(label, thetas, grouped_datasets, set);
+
+				self.wtr.write_record([
+					iteration.to_string(),
+					label.clone(),
+					set.to_string(),
+					loss.to_string(),
+					accuracy.to_string(),
+				])?;
+			}
+		}
+
+		Ok(())
+	}
+
+	pub fn flush(&mut self) -> io::Result<()> {
+		self.wtr.flush()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use float::Float;
+
+	use super::*;
+	use crate::prepare::Datasets;
+
+	fn grouped_datasets() -> GroupedDatasets {
+		[
+			(
+				String::from("a"),
+				Datasets {
+					training: vec![vec![1.0]],
+					testing: vec![],
+				},
+			),
+			(
+				String::from("b"),
+				Datasets {
+					training: vec![vec![-1.0]],
+					testing: vec![vec![-1.0], vec![1.0]],
+				},
+			),
+		]
+		.into_iter()
+		.collect()
+	}
+
+	/// `(iteration, class, set, loss, accuracy)` of every row of the log at `path`
+	fn read(path: &Path) -> Vec<(usize, String, String, Float, Float)> {
+		let mut rdr = csv::Reader::from_path(path).unwrap();
+
+		assert_eq!(rdr.headers().unwrap(), HEADER.to_vec());
+
+		rdr.deserialize().map(Result::unwrap).collect()
+	}
+
+	#[test]
+	fn test_record() {
+		let model = Model {
+			weights: [
+				(String::from("a"), vec![10.0]),
+				(String::from("b"), vec![-10.0]),
+			]
+			.into_iter()
+			.collect(),
+			..Default::default()
+		};
+
+		let path = Path::new("/tmp/cargo_test_dslr_history.csv");
+
+		let mut history = History::create(path).unwrap();
+		history.record(3, &grouped_datasets(), &model).unwrap();
+		history.flush().unwrap();
+
+		let rows = read(path);
+
+		// the test rows are all b, the classifier of a wrongly accepts the second one
+		assert_eq!(
+			rows.iter()
+				.map(|(iteration, class, set, _, accuracy)| (
+					*iteration,
+					class.as_str(),
+					set.as_str(),
+					*accuracy
+				))
+				.collect::<Vec<_>>(),
+			[
+				(3, "a", "train", 1.0),
+				(3, "a", "test", 0.5),
+				(3, "b", "train", 1.0),
+				(3, "b", "test", 0.5)
+			]
+		);
+		assert!(rows
+			.iter()
+			.filter(|(_, _, set, _, _)| set == "train")
+			.all(|(_, _, _, loss, _)| *loss < 1e-3));
+	}
+}
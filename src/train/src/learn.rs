@@ -1,11 +1,19 @@
+use std::io;
+
 use float::Float;
 use hypothesis::hypothesis;
 use indicatif::ProgressIterator;
 use model::Model;
 
-use crate::{prepare::GroupedDatasets, Args};
+use crate::{history::History, prepare::GroupedDatasets, Args};
 
-pub fn learn(arg: &Args, grouped_datasets: &GroupedDatasets, mut model: Model) -> Model {
+/// records the model before the first iteration and after each one in `history`
+pub fn learn(
+	arg: &Args,
+	grouped_datasets: &GroupedDatasets,
+	mut model: Model,
+	mut history: Option<&mut History>,
+) -> io::Result<Model> {
 	for (label, datasets) in grouped_datasets.iter() {
 		model.weights.insert(
 			label.clone(),
@@ -13,13 +21,21 @@ pub fn learn(arg: &Args, grouped_datasets: &GroupedDatasets, mut model: Model) -
 		);
 	}
 
-	for _ in (0..arg.iteration).progress() {
+	if let Some(history) = history.as_deref_mut() {
+		history.record(0, grouped_datasets, &model)?;
+	}
+
+	for iteration in (1..=arg.iteration).progress() {
 		for (label, thetas) in &mut model.weights {
 			guess(thetas, grouped_datasets, arg.learning_rate, label);
 		}
+
+		if let Some(history) = history.as_deref_mut() {
+			history.record(iteration, grouped_datasets, &model)?;
+		}
 	}
 
-	model
+	Ok(model)
 }
 
 fn guess(thetas: &mut [Float], rows: &GroupedDatasets, learning_rate: Float, label: &str) {
@@ -42,3 +58,71 @@ fn guess(thetas: &mut [Float], rows: &GroupedDatasets, learning_rate: Float, lab
 		*theta -= learning_rate * (sum / count as Float);
 	});
 }
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use clap::Parser;
+
+	use super::*;
+	use crate::prepare::Datasets;
+
+	#[test]
+	fn test_learn_history() {
+		let grouped_datasets: GroupedDatasets = [
+			(
+				String::from("a"),
+				Datasets {
+					training: vec![vec![1.0, 1.0]],
+					testing: vec![vec![1.0, 2.0]],
+				},
+			),
+			(
+				String::from("b"),
+				Datasets {
+					training: vec![vec![1.0, -1.0]],
+					testing: vec![vec![1.0, -2.0]],
+				},
+			),
+		]
+		.into_iter()
+		.collect();
+
+		let args = Args::parse_from(["train", "--iter", "2", "--rate", "1"]);
+		let path = Path::new("/tmp/cargo_test_dslr_learn_history.csv");
+
+		let mut history = History::create(path).unwrap();
+		learn(
+			&args,
+			&grouped_datasets,
+			Model::default(),
+			Some(&mut history),
+		)
+		.unwrap();
+		history.flush().unwrap();
+
+		let rows = csv::Reader::from_path(path)
+			.unwrap()
+			.deserialize()
+			.map(Result::unwrap)
+			.collect::<Vec<(usize, String, String, Float, Float)>>();
+
+		// both sets of both classes before the first iteration and after each one
+		assert_eq!(rows.len(), 3 * 4);
+		assert_eq!(
+			rows.iter().map(|row| row.0).collect::<Vec<_>>(),
+			[0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2]
+		);
+
+		let loss = |iteration: usize| {
+			rows.iter()
+				.find(|row| row.0 == iteration && row.1 == "a" && row.2 == "train")
+				.unwrap()
+				.3
+		};
+		// an untrained classifier costs ln 2 on every row
+		assert!((loss(0) - std::f32::consts::LN_2).abs() < 1e-6);
+		assert!(loss(2) < loss(1) && loss(1) < loss(0));
+	}
+}
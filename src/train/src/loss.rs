@@ -1,5 +1,5 @@
 use float::Float;
-use hypothesis::one_vs_all;
+use hypothesis::{hypothesis, one_vs_all};
 use itertools::Itertools;
 use model::Model;
 use tabled::{
@@ -13,7 +13,7 @@ use tabled::{
 	Table,
 };
 
-use crate::prepare::{Dataset, GroupedDatasets};
+use crate::prepare::{Dataset, GroupedDatasets, Set};
 
 type TableRecord<'s> = [&'s str; 7];
 
@@ -108,11 +108,11 @@ fn fill_table(mut builder: Builder, grouped_datasets: &GroupedDatasets, model: &
 	let mut global_testing_total = 0;
 
 	for (label, datasets) in grouped_datasets.iter().sorted_by_key(|(label, _)| *label) {
-		let training_correct = dataset_loss(label, &datasets.training, model);
+		let training_correct = correct_count(label, &datasets.training, model);
 		let training_total = datasets.training.len();
 		let training_percent = training_correct as Float / training_total as Float * 100.0;
 
-		let testing_correct = dataset_loss(label, &datasets.testing, model);
+		let testing_correct = correct_count(label, &datasets.testing, model);
 		let testing_total = datasets.testing.len();
 		let testing_percent = testing_correct as Float / testing_total as Float * 100.0;
 
@@ -154,7 +154,8 @@ fn fill_table(mut builder: Builder, grouped_datasets: &GroupedDatasets, model: &
 	builder.build()
 }
 
-fn dataset_loss(truth: &str, dataset: &Dataset, model: &Model) -> usize {
+/// rows of `dataset` predicted as `truth`
+pub fn correct_count(truth: &str, dataset: &Dataset, model: &Model) -> usize {
 	let mut correct = 0;
 
 	for row in dataset {
@@ -166,3 +167,104 @@ fn dataset_loss(truth: &str, dataset: &Dataset, model: &Model) -> usize {
 
 	correct
 }
+
+/// probabilities are kept this far from 0 and 1 so their log is finite
+const EPSILON: Float = 1e-7;
+
+/// mean cross entropy of the classifier of `label` over the `set` rows of every label
+pub fn log_loss(
+	label: &str,
+	thetas: &[Float],
+	grouped_datasets: &GroupedDatasets,
+	set: Set,
+) -> Float {
+	let mut sum = 0.0;
+	let mut count = 0;
+
+	for (dataset_label, datasets) in grouped_datasets {
+		let truth = label == dataset_label;
+
+		for row in datasets.rows(set) {
+			let h = hypothesis(row, thetas).clamp(EPSILON, 1.0 - EPSILON);
+
+			sum -= if truth { h.ln() } else { (1.0 - h).ln() };
+		}
+
+		count += datasets.rows(set).len();
+	}
+
+	sum / count.max(1) as Float
+}
+
+/// share of the `set` rows of every label the classifier of `label` gets right,
+/// accepting the rows of `label` and rejecting the others
+pub fn accuracy(
+	label: &str,
+	thetas: &[Float],
+	grouped_datasets: &GroupedDatasets,
+	set: Set,
+) -> Float {
+	let mut correct = 0;
+	let mut count = 0;
+
+	for (dataset_label, datasets) in grouped_datasets {
+		let truth = label == dataset_label;
+
+		for row in datasets.rows(set) {
+			if (hypothesis(row, thetas) >= 0.5) == truth {
+				correct += 1;
+			}
+		}
+
+		count += datasets.rows(set).len();
+	}
+
+	correct as Float / count.max(1) as Float
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prepare::Datasets;
+
+	#[test]
+	fn test_log_loss() {
+		let grouped_datasets: GroupedDatasets = [
+			(
+				String::from("a"),
+				Datasets {
+					training: vec![vec![1.0]],
+					testing: vec![],
+				},
+			),
+			(
+				String::from("b"),
+				Datasets {
+					training: vec![vec![-1.0]],
+					testing: vec![],
+				},
+			),
+		]
+		.into_iter()
+		.collect();
+
+		// an undecided classifier costs ln 2 on every row
+		let loss = log_loss("a", &[0.0], &grouped_datasets, Set::Training);
+		assert!((loss - std::f32::consts::LN_2).abs() < 1e-6);
+
+		assert!(log_loss("a", &[10.0], &grouped_datasets, Set::Training) < 1e-3);
+		assert!(log_loss("b", &[10.0], &grouped_datasets, Set::Training) > 9.0);
+
+		assert_eq!(log_loss("a", &[0.0], &grouped_datasets, Set::Testing), 0.0);
+
+		assert_eq!(
+			accuracy("a", &[10.0], &grouped_datasets, Set::Training),
+			1.0
+		);
+		assert_eq!(
+			accuracy("b", &[10.0], &grouped_datasets, Set::Training),
+			0.0
+		);
+		assert_eq!(accuracy("a", &[0.0], &grouped_datasets, Set::Testing), 0.0);
+	}
+}
@@ -1,10 +1,14 @@
+mod history;
 mod learn;
 mod loss;
 mod prepare;
 
 use std::path::PathBuf;
 
+use analyze::Report;
 use clap::{Parser, ValueEnum};
+use date::Expansion;
+use history::History;
 use polars::error::PolarsResult;
 
 use float::Float;
@@ -38,16 +42,45 @@ pub struct Args {
 	/// data normalization method
 	#[clap(long = "norm", short = 'n', default_value = "std-dev")]
 	normalization: Normalization,
+
+	/// path to a json report of precomputed statistics (from `describe --report`)
+	#[clap(long)]
+	stats: Option<PathBuf>,
+
+	/// path to write the loss and accuracy of every class at each iteration to
+	#[clap(long)]
+	log: Option<PathBuf>,
+
+	/// date column expanded into year, month, day of year and weekday features,
+	/// recorded in the model so predictions expand it the same way
+	#[clap(long)]
+	date: Option<String>,
+
+	/// chrono format of the dates of `--date`
+	#[clap(long, default_value = date::DEFAULT_FORMAT)]
+	date_format: String,
 }
 
 fn main() -> PolarsResult<()> {
 	let args = Args::parse();
 
-	let df = load::load(&args.path)?;
+	let mut df = load::load(&args.path)?;
+
+	if let Some(column) = &args.date {
+		Expansion::new(column, &args.date_format).apply(&mut df)?;
+	}
+
+	let report = args.stats.as_deref().map(Report::read).transpose()?;
+
+	let (grouped_datasets, model) = prepare::prepare(&args, df, report.as_ref());
+
+	let mut history = args.log.as_deref().map(History::create).transpose()?;
 
-	let (grouped_datasets, model) = prepare::prepare(&args, df);
+	let model = learn::learn(&args, &grouped_datasets, model, history.as_mut())?;
 
-	let model = learn::learn(&args, &grouped_datasets, model);
+	if let Some(history) = &mut history {
+		history.flush()?;
+	}
 
 	loss::print_result(&grouped_datasets, &model);
 
@@ -3,9 +3,9 @@ mod parse;
 
 use std::collections::HashMap;
 
-use analyze::Analysis;
+use analyze::{Analysis, Report};
 use float::Float;
-use model::Model;
+use model::{DateColumn, Model};
 use polars::prelude::*;
 
 use crate::{Args, Normalization};
@@ -17,16 +17,49 @@ pub struct Datasets {
 	pub training: Dataset,
 	pub testing: Dataset,
 }
+
+/// one of the two halves the rows of a label are split into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Set {
+	Training,
+	Testing,
+}
+
+impl std::fmt::Display for Set {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			Set::Training => write!(f, "train"),
+			Set::Testing => write!(f, "test"),
+		}
+	}
+}
+
+impl Datasets {
+	pub fn rows(&self, set: Set) -> &Dataset {
+		match set {
+			Set::Training => &self.training,
+			Set::Testing => &self.testing,
+		}
+	}
+}
+
 pub type Dataset = Vec<Features>;
 pub type Features = Vec<Float>;
 
-pub fn prepare(args: &Args, df: DataFrame) -> (GroupedDatasets, Model) {
-	let analysis = features_analysis(&df);
+pub fn prepare(args: &Args, df: DataFrame, report: Option<&Report>) -> (GroupedDatasets, Model) {
+	let analysis = match report {
+		Some(report) => report_analysis(&df, report),
+		None => features_analysis(&df),
+	};
 
 	let mut grouped_datasets = parse::datasets(&df, &analysis);
 
 	let mut model = store_analysis(&analysis, args);
 	model.label_name = label_name(&df);
+	model.date = args.date.as_ref().map(|column| DateColumn {
+		column: column.clone(),
+		format: args.date_format.clone(),
+	});
 
 	normalize::normalize(args, &mut grouped_datasets, &analysis);
 
@@ -34,15 +67,20 @@ pub fn prepare(args: &Args, df: DataFrame) -> (GroupedDatasets, Model) {
 }
 
 fn features_analysis(df: &DataFrame) -> Vec<Analysis> {
-	let mut features_analysis = Vec::new();
-
-	for col in df.get_columns() {
-		if col.dtype().is_float() {
-			features_analysis.push(Analysis::from(col));
-		}
-	}
+	analyze::par_analyze(df, |col| col.dtype().is_float())
+}
 
-	features_analysis
+fn report_analysis(df: &DataFrame, report: &Report) -> Vec<Analysis> {
+	df.get_columns()
+		.iter()
+		.filter(|col| col.dtype().is_float())
+		.map(|col| {
+			report
+				.get(col.name())
+				.cloned()
+				.unwrap_or_else(|| Analysis::from(col))
+		})
+		.collect()
 }
 
 fn label_name(df: &DataFrame) -> String {
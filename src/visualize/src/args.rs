@@ -1,38 +1,145 @@
-use std::path::PathBuf;
+use std::{any::Any, path::PathBuf};
 
-use clap::{arg, command, value_parser};
+use clap::{arg, command, value_parser, ArgMatches, Command};
+use date::Expansion;
+use polars::prelude::*;
+
+use crate::{Backend, Palette, DEFAULT_LABEL};
 
 pub struct Args {
 	pub csv: PathBuf,
 	pub output: PathBuf,
+	pub label: String,
+	pub palette: Palette,
+	pub backend: Backend,
+	/// columns of the grid of subplots, computed from their number by default
+	pub columns: Option<usize>,
+	/// date column expanded into numeric features, and its format
+	pub date: (String, String),
 }
 
 pub fn parse(default_output: &'static str) -> Args {
-	let args = command!()
-		.arg(
-			arg!(--csv <path>)
-				.help("dataset csv path")
-				.value_parser(value_parser!(PathBuf))
-				.default_value("./datasets/train.csv")
-				.required(false),
-		)
-		.arg(
-			arg!(--output <path>)
-				.help("output path of the graph")
-				.value_parser(value_parser!(PathBuf))
-				.default_value(default_output)
-				.required(false),
-		)
-		.get_matches();
-
-	Args {
-		csv: args
-			.get_one::<PathBuf>("csv")
-			.expect("default ensures there is always a value")
-			.to_owned(),
-		output: args
-			.get_one::<PathBuf>("output")
-			.expect("default ensures there is always a value")
-			.to_owned(),
+	Args::from_matches(&command(default_output).get_matches())
+}
+
+/// arguments shared by every binary, to which a binary can add its own
+pub fn command(default_output: &'static str) -> Command {
+	command_without(default_output, &[])
+}
+
+/// the shared arguments but `skipped`, for a binary with no use for them
+pub fn command_without(default_output: &'static str, skipped: &[&str]) -> Command {
+	let args = [
+		arg!(--csv <path>)
+			.help("dataset csv path")
+			.value_parser(value_parser!(PathBuf))
+			.default_value("./datasets/train.csv")
+			.required(false),
+		arg!(--output <path>)
+			.help(
+				"output path of the graph, its extension (png, jpg, webp, svg, pdf or html) sets the format \
+				(html pages load plotly.js from its cdn)",
+			)
+			.value_parser(value_parser!(PathBuf))
+			.default_value(default_output)
+			.required(false),
+		arg!(--label <column>)
+			.help("column of the labels the data is colored by")
+			.default_value(DEFAULT_LABEL)
+			.required(false),
+		arg!(--palette <palette>)
+			.help("colors of the labels: houses, category or comma separated #rrggbb colors")
+			.value_parser(value_parser!(Palette))
+			.default_value("houses")
+			.required(false),
+		arg!(--backend <backend>)
+			.help("plotly writes to --output, terminal prints to stdout")
+			.value_parser(value_parser!(Backend))
+			.default_value("plotly")
+			.required(false),
+		arg!(--columns <columns>)
+			.help("number of columns of the grid of subplots")
+			.value_parser(value_parser!(usize))
+			.required(false),
+		arg!(--date <column>)
+			.help("date column expanded into year, month, day of year and weekday, if present")
+			.default_value("Birthday")
+			.required(false),
+		arg!(--"date-format" <format>)
+			.help("chrono format of the dates")
+			.default_value(date::DEFAULT_FORMAT)
+			.required(false),
+	];
+
+	command!().args(
+		args.into_iter()
+			.filter(|arg| !skipped.contains(&arg.get_id().as_str())),
+	)
+}
+
+impl Args {
+	pub fn from_matches(args: &ArgMatches) -> Self {
+		Args {
+			csv: args
+				.get_one::<PathBuf>("csv")
+				.expect("default ensures there is always a value")
+				.to_owned(),
+			output: args
+				.get_one::<PathBuf>("output")
+				.expect("default ensures there is always a value")
+				.to_owned(),
+			label: skippable(args, "label"),
+			palette: skippable(args, "palette"),
+			backend: *args
+				.get_one::<Backend>("backend")
+				.expect("default ensures there is always a value"),
+			columns: args.try_get_one::<usize>("columns").ok().flatten().copied(),
+			date: (skippable(args, "date"), skippable(args, "date-format")),
+		}
+	}
+
+	/// does nothing when the dataset has no such date column
+	pub fn expand_date(&self, df: &mut DataFrame) -> PolarsResult<()> {
+		let (column, format) = &self.date;
+
+		if df.column(column).is_err() {
+			return Ok(());
+		}
+
+		Expansion::new(column, format).apply(df)
+	}
+}
+
+/// value of a shared argument with a default, the type's default when the binary skipped it
+fn skippable<T: Any + Clone + Default + Send + Sync + 'static>(args: &ArgMatches, id: &str) -> T {
+	args.try_get_one::<T>(id)
+		.ok()
+		.flatten()
+		.cloned()
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_command_without() {
+		let matches = command_without("plot.png", &["label", "columns"]).try_get_matches_from([
+			"plot",
+			"--columns",
+			"2",
+		]);
+		assert!(matches.is_err());
+
+		let matches = command_without("plot.png", &["label", "columns"])
+			.try_get_matches_from(["plot"])
+			.unwrap();
+		let args = Args::from_matches(&matches);
+
+		assert_eq!(args.label, "");
+		assert_eq!(args.columns, None);
+		assert_eq!(args.palette, Palette::Houses);
+		assert_eq!(args.output, PathBuf::from("plot.png"));
 	}
 }
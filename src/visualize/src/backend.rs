@@ -0,0 +1,30 @@
+/// what draws the plots
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Backend {
+	/// an image or html page written to `--output`
+	#[default]
+	Plotly,
+	/// braille and block characters printed to stdout
+	Terminal,
+}
+
+impl std::fmt::Display for Backend {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			Backend::Plotly => write!(f, "plotly"),
+			Backend::Terminal => write!(f, "terminal"),
+		}
+	}
+}
+
+impl std::str::FromStr for Backend {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"plotly" => Ok(Backend::Plotly),
+			"terminal" => Ok(Backend::Terminal),
+			_ => Err(format!("{s} is not a backend (plotly or terminal)")),
+		}
+	}
+}
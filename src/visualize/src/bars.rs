@@ -0,0 +1,119 @@
+use plotly::{histogram::HistNorm, layout};
+
+/// what the height of a histogram bar is
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Norm {
+	#[default]
+	Count,
+	/// percent of the values of the label
+	Percent,
+	/// count over the number of values and the bin width, the area of a label is 1
+	Density,
+}
+
+impl std::fmt::Display for Norm {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			Norm::Count => write!(f, "count"),
+			Norm::Percent => write!(f, "percent"),
+			Norm::Density => write!(f, "density"),
+		}
+	}
+}
+
+impl std::str::FromStr for Norm {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"count" => Ok(Norm::Count),
+			"percent" => Ok(Norm::Percent),
+			"density" => Ok(Norm::Density),
+			_ => Err(format!(
+				"{s} is not a histogram normalization (count, percent or density)"
+			)),
+		}
+	}
+}
+
+impl Norm {
+	pub fn plotly(&self) -> HistNorm {
+		match self {
+			Norm::Count => HistNorm::Default,
+			Norm::Percent => HistNorm::Percent,
+			Norm::Density => HistNorm::ProbabilityDensity,
+		}
+	}
+
+	/// factor from a probability density to the height of a bar
+	/// of `width` for `count` values
+	pub fn scale(&self, count: usize, width: f64) -> f64 {
+		match self {
+			Norm::Count => count as f64 * width,
+			Norm::Percent => 100.0 * width,
+			Norm::Density => 1.0,
+		}
+	}
+}
+
+/// how the bars of the labels share a bin
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum BarMode {
+	#[default]
+	Overlay,
+	Stack,
+	Group,
+}
+
+impl std::fmt::Display for BarMode {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			BarMode::Overlay => write!(f, "overlay"),
+			BarMode::Stack => write!(f, "stack"),
+			BarMode::Group => write!(f, "group"),
+		}
+	}
+}
+
+impl std::str::FromStr for BarMode {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"overlay" => Ok(BarMode::Overlay),
+			"stack" => Ok(BarMode::Stack),
+			"group" => Ok(BarMode::Group),
+			_ => Err(format!("{s} is not a bar mode (overlay, stack or group)")),
+		}
+	}
+}
+
+impl BarMode {
+	pub fn plotly(&self) -> layout::BarMode {
+		match self {
+			BarMode::Overlay => layout::BarMode::Overlay,
+			BarMode::Stack => layout::BarMode::Stack,
+			BarMode::Group => layout::BarMode::Group,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!("density".parse(), Ok(Norm::Density));
+		assert_eq!("stack".parse(), Ok(BarMode::Stack));
+		assert!("probability".parse::<Norm>().is_err());
+		assert!("relative".parse::<BarMode>().is_err());
+	}
+
+	#[test]
+	fn test_scale() {
+		assert_eq!(Norm::Count.scale(200, 0.5), 100.0);
+		assert_eq!(Norm::Percent.scale(200, 0.5), 50.0);
+		assert_eq!(Norm::Density.scale(200, 0.5), 1.0);
+	}
+}
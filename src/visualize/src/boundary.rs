@@ -0,0 +1,130 @@
+use float::Float;
+use hypothesis::one_vs_all;
+use model::Model;
+use polars::prelude::*;
+
+/// features of a model, the float columns of its training dataset in order
+pub fn features(df: &DataFrame) -> Vec<String> {
+	df.get_columns()
+		.iter()
+		.filter(|series| series.dtype().is_float())
+		.map(|series| series.name().to_string())
+		.collect()
+}
+
+/// a model evaluated on two of its features, the others held at their means
+pub struct Slice<'m> {
+	model: &'m Model,
+	x: usize,
+	y: usize,
+	/// normalized means of every feature
+	base: Vec<Float>,
+}
+
+impl<'m> Slice<'m> {
+	pub fn new(model: &'m Model, features: &[String], x: &str, y: &str) -> Result<Self, String> {
+		if features.len() != model.means.len() {
+			return Err(format!(
+				"the model has {} features but the dataset has {} float columns",
+				model.means.len(),
+				features.len()
+			));
+		}
+
+		let index = |name: &str| {
+			features
+				.iter()
+				.position(|feature| feature == name)
+				.ok_or_else(|| format!("{name} is not a feature ({})", features.join(", ")))
+		};
+
+		let base = model
+			.means
+			.iter()
+			.zip(&model.normalization_factors)
+			.map(|(mean, (a, b))| (mean - a) / b)
+			.collect();
+
+		Ok(Self {
+			model,
+			x: index(x)?,
+			y: index(y)?,
+			base,
+		})
+	}
+
+	/// label predicted for the values `x` and `y` of the two features
+	pub fn label(&self, x: f64, y: f64) -> String {
+		let mut row = self.base.clone();
+
+		for (i, value) in [(self.x, x), (self.y, y)] {
+			let (a, b) = self.model.normalization_factors[i];
+			row[i] = (value as Float - a) / b;
+		}
+
+		one_vs_all(&row, self.model)
+	}
+
+	/// label of every point of the grid, `grid[y][x]`
+	pub fn grid(&self, xs: &[f64], ys: &[f64]) -> Vec<Vec<String>> {
+		ys.iter()
+			.map(|&y| xs.iter().map(|&x| self.label(x, y)).collect())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn model() -> Model {
+		Model {
+			label_name: String::from("side"),
+			weights: [
+				(String::from("left"), vec![-1.0, 0.0, 1.0]),
+				(String::from("right"), vec![1.0, 0.0, -1.0]),
+			]
+			.into_iter()
+			.collect(),
+			normalization_factors: vec![(10.0, 2.0), (0.0, 1.0), (0.0, 1.0)],
+			means: vec![10.0, 0.0, 0.0],
+			date: None,
+		}
+	}
+
+	fn names(names: &[&str]) -> Vec<String> {
+		names.iter().map(|name| name.to_string()).collect()
+	}
+
+	#[test]
+	fn test_slice() {
+		let model = model();
+		let slice = Slice::new(&model, &names(&["a", "b", "c"]), "a", "b").unwrap();
+
+		assert_eq!(slice.label(12.0, 0.0), "right");
+		assert_eq!(slice.label(8.0, 0.0), "left");
+
+		assert_eq!(
+			slice.grid(&[8.0, 12.0], &[0.0]),
+			[[String::from("left"), String::from("right")]]
+		);
+	}
+
+	#[test]
+	fn test_slice_means() {
+		let mut model = model();
+		// `c` held at its mean outweighs `a`
+		model.means[2] = 10.0;
+		let slice = Slice::new(&model, &names(&["a", "b", "c"]), "a", "b").unwrap();
+
+		assert_eq!(slice.label(12.0, 0.0), "left");
+	}
+
+	#[test]
+	fn test_slice_error() {
+		let model = model();
+
+		assert!(Slice::new(&model, &names(&["a", "b"]), "a", "b").is_err());
+		assert!(Slice::new(&model, &names(&["a", "b", "c"]), "a", "d").is_err());
+	}
+}
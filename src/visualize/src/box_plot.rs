@@ -0,0 +1,176 @@
+use std::error::Error;
+
+use clap::arg;
+use plotly::Plot;
+
+use visualize::{
+	annotation,
+	args::{self, Args},
+	feature::{self, Group},
+	image,
+	layout::{self, Grid},
+	stats, terminal, trace, Backend, Labels, PlotType,
+};
+
+const TERMINAL_COLUMNS: usize = 3;
+
+/// points the density of a violin is evaluated at
+const VIOLIN_POINTS: usize = 64;
+/// half the width of a violin, labels are 1 apart
+const VIOLIN_WIDTH: f64 = 0.4;
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let matches = args::command("box_plot.png")
+		.arg(arg!(--violin "draw the density of each label instead of its box"))
+		.get_matches();
+	let args = Args::from_matches(&matches);
+
+	let mut dataset = load::load(&args.csv)?;
+
+	args.expand_date(&mut dataset)?;
+
+	let labels = Labels::new(&dataset, &args.label, &args.palette)?;
+	let groups = feature::groups(&dataset, &labels)?;
+
+	match args.backend {
+		Backend::Plotly => plot(&groups, &labels, &args, matches.get_flag("violin")),
+		Backend::Terminal => {
+			print(&groups, args.columns.unwrap_or(TERMINAL_COLUMNS));
+			Ok(())
+		}
+	}
+}
+
+fn plot(
+	groups: &[Group],
+	labels: &Labels,
+	args: &Args,
+	violin: bool,
+) -> Result<(), Box<dyn Error>> {
+	let mut plot = Plot::new();
+
+	let names = feature::names(groups);
+
+	let grid = Grid::new(names.len(), args.columns);
+	let plot_type = if violin {
+		PlotType::Violin
+	} else {
+		PlotType::Box
+	};
+	let mut layout = layout::build(plot_type, grid, &labels.name);
+
+	for (i, name) in names.iter().enumerate() {
+		let plot_index = i + 1;
+
+		for (position, group) in groups.iter().enumerate() {
+			let Some(col) = group.feature(name) else {
+				continue;
+			};
+
+			if !violin {
+				plot.add_trace(trace::box_plot(
+					col.to_vec(),
+					&group.label,
+					group.color,
+					plot_index,
+				));
+				continue;
+			}
+
+			let mut sorted = col.to_vec();
+			sorted.sort_by(f64::total_cmp);
+
+			let center = position as f64;
+
+			if let Some((x, y)) = outline(&sorted, center) {
+				plot.add_trace(trace::violin(x, y, &group.label, group.color, plot_index));
+			}
+
+			let outliers = stats::outliers(&sorted);
+			plot.add_trace(trace::outliers(
+				vec![center; outliers.len()],
+				outliers,
+				group.color,
+				plot_index,
+			));
+		}
+
+		layout.add_annotation(annotation(plot_index, name));
+	}
+
+	plot.set_layout(layout);
+
+	let (width, height) = grid.image_size();
+	image::write(&plot, &args.output, width, height)?;
+
+	Ok(())
+}
+
+/// closed outline of the density of sorted values, mirrored around `center`,
+/// `None` when the values are constant
+fn outline(sorted: &[f64], center: f64) -> Option<(Vec<f64>, Vec<f64>)> {
+	let bandwidth = stats::bandwidth(sorted)?;
+	let (min, max) = (*sorted.first()?, *sorted.last()?);
+
+	let y = stats::linspace(min, max, VIOLIN_POINTS);
+	let density = stats::kde(sorted, bandwidth, &y);
+
+	let peak = density.iter().copied().fold(0.0, f64::max);
+	if peak <= 0.0 {
+		return None;
+	}
+
+	let half = density
+		.iter()
+		.map(|d| d / peak * VIOLIN_WIDTH)
+		.collect::<Vec<_>>();
+
+	let x = half
+		.iter()
+		.map(|h| center + h)
+		.chain(half.iter().rev().map(|h| center - h))
+		.collect();
+	let y = y.iter().chain(y.iter().rev()).copied().collect();
+
+	Some((x, y))
+}
+
+fn print(groups: &[Group], columns: usize) {
+	let width = terminal::panel_width(columns);
+
+	let panels = feature::names(groups)
+		.iter()
+		.map(|name| {
+			let series = groups
+				.iter()
+				.filter_map(|group| Some((group.color, group.feature(name)?)))
+				.collect::<Vec<_>>();
+
+			terminal::box_plot(name, &series, width)
+		})
+		.collect::<Vec<_>>();
+
+	println!("{}\n", terminal::legend(groups));
+	print!("{}", terminal::grid(&panels, width, columns));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_outline() {
+		let (x, y) = outline(&[0.0, 1.0, 1.0, 2.0], 3.0).unwrap();
+
+		assert_eq!(x.len(), 2 * VIOLIN_POINTS);
+		assert_eq!((y[0], y[VIOLIN_POINTS - 1]), (0.0, 2.0));
+		assert!(x[..VIOLIN_POINTS]
+			.iter()
+			.all(|x| (3.0..=3.0 + VIOLIN_WIDTH).contains(x)));
+		assert!(x[VIOLIN_POINTS..]
+			.iter()
+			.all(|x| (3.0 - VIOLIN_WIDTH..=3.0).contains(x)));
+
+		assert_eq!(outline(&[1.0, 1.0], 0.0), None);
+	}
+}
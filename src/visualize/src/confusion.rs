@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+
+/// column the rows of the predictions and of the truth are matched on
+pub const INDEX: &str = "Index";
+
+/// counts of every pair of true and predicted labels
+#[derive(Debug, PartialEq)]
+pub struct Confusion {
+	/// sorted labels, true or predicted
+	pub labels: Vec<String>,
+	/// `counts[truth][prediction]`
+	pub counts: Vec<Vec<usize>>,
+}
+
+impl Confusion {
+	/// from `(truth, prediction)` pairs
+	pub fn new(pairs: &[(String, String)]) -> Self {
+		let mut labels = pairs
+			.iter()
+			.flat_map(|(truth, prediction)| [truth.clone(), prediction.clone()])
+			.collect::<Vec<_>>();
+		labels.sort();
+		labels.dedup();
+
+		let index = |label: &str| {
+			labels
+				.binary_search_by(|l| l.as_str().cmp(label))
+				.expect("every label is listed")
+		};
+
+		let mut counts = vec![vec![0; labels.len()]; labels.len()];
+		for (truth, prediction) in pairs {
+			counts[index(truth)][index(prediction)] += 1;
+		}
+
+		Self { labels, counts }
+	}
+
+	pub fn total(&self) -> usize {
+		self.counts.iter().flatten().sum()
+	}
+
+	/// share of the rows predicted right, `None` without rows
+	pub fn accuracy(&self) -> Option<f64> {
+		let right = (0..self.labels.len()).map(|i| self.counts[i][i]).sum();
+
+		ratio(right, self.total())
+	}
+
+	/// share of the rows predicted as the `i`th label that are of that label
+	pub fn precision(&self, i: usize) -> Option<f64> {
+		ratio(
+			self.counts[i][i],
+			self.counts.iter().map(|row| row[i]).sum(),
+		)
+	}
+
+	/// share of the rows of the `i`th label predicted as that label
+	pub fn recall(&self, i: usize) -> Option<f64> {
+		ratio(self.counts[i][i], self.counts[i].iter().sum())
+	}
+
+	/// harmonic mean of the precision and the recall
+	pub fn f1(&self, i: usize) -> Option<f64> {
+		match (self.precision(i)?, self.recall(i)?) {
+			(p, r) if p + r > 0.0 => Some(2.0 * p * r / (p + r)),
+			_ => Some(0.0),
+		}
+	}
+}
+
+fn ratio(count: usize, total: usize) -> Option<f64> {
+	(total > 0).then(|| count as f64 / total as f64)
+}
+
+/// `(truth, prediction)` of the rows with both labels, matched on [`INDEX`]
+/// when both files have it and on their order otherwise
+pub fn pairs(
+	truth: &DataFrame,
+	predictions: &DataFrame,
+	label: &str,
+) -> PolarsResult<Vec<(String, String)>> {
+	let labels = |df: &DataFrame| -> PolarsResult<Vec<Option<String>>> {
+		Ok(df
+			.column(label)?
+			.cast(&DataType::String)?
+			.str()?
+			.into_iter()
+			.map(|label| label.map(str::to_owned))
+			.collect())
+	};
+	let indexes = |df: &DataFrame| -> PolarsResult<Option<Vec<Option<i64>>>> {
+		let Ok(index) = df.column(INDEX) else {
+			return Ok(None);
+		};
+
+		Ok(Some(
+			index.cast(&DataType::Int64)?.i64()?.into_iter().collect(),
+		))
+	};
+
+	let (truth_labels, prediction_labels) = (labels(truth)?, labels(predictions)?);
+
+	let pairs = match (indexes(truth)?, indexes(predictions)?) {
+		(Some(truth_indexes), Some(prediction_indexes)) => {
+			let truth = truth_indexes
+				.into_iter()
+				.zip(truth_labels)
+				.filter_map(|(index, label)| Some((index?, label?)))
+				.collect::<HashMap<_, _>>();
+
+			prediction_indexes
+				.into_iter()
+				.zip(prediction_labels)
+				.filter_map(|(index, prediction)| {
+					let truth = truth.get(&index?)?;
+
+					Some((truth.clone(), prediction?))
+				})
+				.collect()
+		}
+		_ => truth_labels
+			.into_iter()
+			.zip(prediction_labels)
+			.filter_map(|(truth, prediction)| truth.zip(prediction))
+			.collect(),
+	};
+
+	Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn confusion() -> Confusion {
+		Confusion::new(
+			&[("a", "a"), ("a", "a"), ("a", "b"), ("b", "b"), ("c", "b")]
+				.map(|(truth, prediction)| (truth.to_owned(), prediction.to_owned())),
+		)
+	}
+
+	#[test]
+	fn test_confusion() {
+		let confusion = confusion();
+
+		assert_eq!(confusion.labels, ["a", "b", "c"]);
+		assert_eq!(confusion.counts, [[2, 1, 0], [0, 1, 0], [0, 1, 0]]);
+		assert_eq!(confusion.total(), 5);
+		assert_eq!(confusion.accuracy(), Some(0.6));
+	}
+
+	#[test]
+	fn test_metrics() {
+		let confusion = confusion();
+
+		assert_eq!(confusion.precision(0), Some(1.0));
+		assert_eq!(confusion.recall(0), Some(2.0 / 3.0));
+		assert!((confusion.f1(0).unwrap() - 0.8).abs() < 1e-12);
+
+		assert_eq!(confusion.precision(1), Some(1.0 / 3.0));
+		assert_eq!(confusion.recall(1), Some(1.0));
+
+		// never predicted
+		assert_eq!(confusion.precision(2), None);
+		assert_eq!(confusion.recall(2), Some(0.0));
+		assert_eq!(confusion.f1(2), None);
+	}
+
+	#[test]
+	fn test_pairs() {
+		let truth = DataFrame::new(vec![
+			Series::new(INDEX.into(), &[0, 1, 2]),
+			Series::new("house".into(), &[Some("a"), Some("b"), None]),
+		])
+		.unwrap();
+		let predictions = DataFrame::new(vec![
+			Series::new(INDEX.into(), &[2, 1, 0, 3]),
+			Series::new("house".into(), &["c", "c", "a", "a"]),
+		])
+		.unwrap();
+
+		let pair = |truth: &str, prediction: &str| (truth.to_owned(), prediction.to_owned());
+
+		assert_eq!(
+			pairs(&truth, &predictions, "house").unwrap(),
+			[pair("b", "c"), pair("a", "a")]
+		);
+
+		let predictions = predictions.drop(INDEX).unwrap();
+
+		assert_eq!(
+			pairs(&truth, &predictions, "house").unwrap(),
+			[pair("a", "c"), pair("b", "c")]
+		);
+	}
+}
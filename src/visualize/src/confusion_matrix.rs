@@ -0,0 +1,179 @@
+use std::{error::Error, path::PathBuf};
+
+use clap::{arg, value_parser};
+use plotly::{
+	common::{Anchor, ColorScale, ColorScalePalette, Font},
+	layout::{Annotation, Axis},
+	HeatMap, Plot,
+};
+
+use visualize::{
+	annotation,
+	args::{self, Args},
+	confusion::{self, Confusion},
+	image,
+	layout::{self, Grid},
+	terminal, trace, Backend, Labels, PlotType,
+};
+
+const TERMINAL_COLUMNS: usize = 3;
+/// the matrix and a chart for each metric
+const PANELS: usize = 4;
+const COLUMNS: usize = 2;
+
+type Metric = fn(&Confusion, usize) -> Option<f64>;
+
+const METRICS: [(&str, Metric); 3] = [
+	("precision", Confusion::precision),
+	("recall", Confusion::recall),
+	("F1", Confusion::f1),
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let matches = args::command("confusion.png")
+		.mut_arg("csv", |arg| arg.help("csv path of the true labels"))
+		.arg(
+			arg!(--predictions <path>)
+				.help("csv path of the predicted labels, as written by `logreg_predict`")
+				.value_parser(value_parser!(PathBuf))
+				.default_value("houses.csv")
+				.required(false),
+		)
+		.get_matches();
+	let args = Args::from_matches(&matches);
+
+	let truth = load::load(&args.csv)?;
+	let predictions = load::load(
+		matches
+			.get_one::<PathBuf>("predictions")
+			.expect("default ensures there is always a value"),
+	)?;
+
+	let pairs = confusion::pairs(&truth, &predictions, &args.label)?;
+	if pairs.is_empty() {
+		return Err(format!("no prediction has a true {}", args.label).into());
+	}
+
+	let confusion = Confusion::new(&pairs);
+	let labels = Labels::new(&truth, &args.label, &args.palette)?;
+
+	let accuracy = confusion.accuracy().unwrap_or_default() * 100.0;
+	println!(
+		"accuracy: {accuracy:.2}% of {} predictions",
+		confusion.total()
+	);
+
+	match args.backend {
+		Backend::Plotly => plot(&confusion, &labels, &args),
+		Backend::Terminal => {
+			print(
+				&confusion,
+				&labels,
+				args.columns.unwrap_or(TERMINAL_COLUMNS),
+			);
+			Ok(())
+		}
+	}
+}
+
+fn plot(confusion: &Confusion, labels: &Labels, args: &Args) -> Result<(), Box<dyn Error>> {
+	let mut plot = Plot::new();
+
+	let grid = Grid::new(PANELS, Some(args.columns.unwrap_or(COLUMNS)));
+	let mut layout = layout::build(PlotType::Confusion, grid, &labels.name)
+		.x_axis(Axis::new().title("prediction"))
+		.y_axis(Axis::new().title("truth"));
+
+	// rows are drawn from the bottom, reversed so the first label is on top
+	let y = confusion.labels.iter().rev().cloned().collect::<Vec<_>>();
+	let z = confusion.counts.iter().rev().cloned().collect::<Vec<_>>();
+
+	plot.add_trace(
+		HeatMap::new(confusion.labels.clone(), y, z)
+			.color_scale(ColorScale::Palette(ColorScalePalette::Blues))
+			.reverse_scale(true)
+			.show_scale(false)
+			.x_axis("x1")
+			.y_axis("y1"),
+	);
+
+	for (row, truth) in confusion.counts.iter().zip(&confusion.labels) {
+		for (count, prediction) in row.iter().zip(&confusion.labels) {
+			layout.add_annotation(
+				Annotation::new()
+					.x(prediction.clone())
+					.y(truth.clone())
+					.x_ref("x1")
+					.y_ref("y1")
+					.text(count.to_string())
+					.font(Font::new().size(12))
+					.show_arrow(false),
+			);
+		}
+	}
+
+	layout.add_annotation(annotation(1, "confusion matrix"));
+
+	for (i, (name, metric)) in METRICS.iter().enumerate() {
+		let plot_index = i + 2;
+
+		for (j, label) in confusion.labels.iter().enumerate() {
+			let value = metric(confusion, j);
+
+			plot.add_trace(trace::bar(
+				label,
+				value,
+				labels.color(label),
+				plot_index,
+				i == 0,
+			));
+
+			// like the terminal, rather than a bar of 0
+			if value.is_none() {
+				layout.add_annotation(
+					Annotation::new()
+						.x(label.clone())
+						.y(0.0)
+						.x_ref(format!("x{plot_index}"))
+						.y_ref(format!("y{plot_index}"))
+						.y_anchor(Anchor::Bottom)
+						.text("n/a")
+						.show_arrow(false),
+				);
+			}
+		}
+
+		layout.add_annotation(annotation(plot_index, name));
+	}
+
+	plot.set_layout(layout);
+
+	let (width, height) = grid.image_size();
+	image::write(&plot, &args.output, width, height)?;
+
+	Ok(())
+}
+
+fn print(confusion: &Confusion, labels: &Labels, columns: usize) {
+	let width = terminal::panel_width(columns);
+
+	let panels = METRICS
+		.iter()
+		.map(|(name, metric)| {
+			let bars = confusion
+				.labels
+				.iter()
+				.enumerate()
+				.map(|(i, label)| (labels.color(label), label.as_str(), metric(confusion, i)))
+				.collect::<Vec<_>>();
+
+			terminal::bars(name, &bars, width)
+		})
+		.collect::<Vec<_>>();
+
+	println!(
+		"\n{}",
+		terminal::confusion(&confusion.labels, &confusion.counts)
+	);
+	print!("{}", terminal::grid(&panels, width, columns));
+}
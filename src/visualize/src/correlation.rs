@@ -0,0 +1,231 @@
+use polars::prelude::*;
+
+use crate::feature;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Method {
+	#[default]
+	Pearson,
+	/// pearson correlation of the ranks, robust to outliers and monotonic relations
+	Spearman,
+}
+
+impl std::fmt::Display for Method {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match *self {
+			Method::Pearson => write!(f, "pearson"),
+			Method::Spearman => write!(f, "spearman"),
+		}
+	}
+}
+
+impl std::str::FromStr for Method {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"pearson" => Ok(Method::Pearson),
+			"spearman" => Ok(Method::Spearman),
+			_ => Err(format!("{s} is not a correlation (pearson or spearman)")),
+		}
+	}
+}
+
+impl Method {
+	pub fn correlation(&self, x: &[f64], y: &[f64]) -> Option<f64> {
+		match self {
+			Method::Pearson => pearson(x, y),
+			Method::Spearman => spearman(x, y),
+		}
+	}
+}
+
+/// pearson correlation coefficient, `None` when a variable is constant
+pub fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+	let n = x.len().min(y.len());
+	if n < 2 {
+		return None;
+	}
+
+	let mean = |v: &[f64]| v[..n].iter().sum::<f64>() / n as f64;
+	let (mean_x, mean_y) = (mean(x), mean(y));
+
+	let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+	for (x, y) in x.iter().zip(y) {
+		let (dx, dy) = (x - mean_x, y - mean_y);
+		covariance += dx * dy;
+		variance_x += dx * dx;
+		variance_y += dy * dy;
+	}
+
+	if variance_x == 0.0 || variance_y == 0.0 {
+		return None;
+	}
+
+	Some(covariance / (variance_x * variance_y).sqrt())
+}
+
+pub fn spearman(x: &[f64], y: &[f64]) -> Option<f64> {
+	pearson(&ranks(x), &ranks(y))
+}
+
+/// rank of each value starting at 1, ties get the mean of their ranks
+fn ranks(values: &[f64]) -> Vec<f64> {
+	let mut order = (0..values.len()).collect::<Vec<_>>();
+	order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+	let mut ranks = vec![0.0; values.len()];
+
+	let mut start = 0;
+	while start < order.len() {
+		let mut end = start + 1;
+		while end < order.len() && values[order[end]] == values[order[start]] {
+			end += 1;
+		}
+
+		let rank = (start + end + 1) as f64 / 2.0;
+		for &i in &order[start..end] {
+			ranks[i] = rank;
+		}
+
+		start = end;
+	}
+
+	ranks
+}
+
+/// correlation of every pair of `names` on the rows where both are set
+pub fn matrix(
+	df: &DataFrame,
+	names: &[String],
+	method: Method,
+) -> PolarsResult<Vec<Vec<Option<f64>>>> {
+	let mut matrix = vec![vec![None; names.len()]; names.len()];
+
+	for (i, x) in names.iter().enumerate() {
+		matrix[i][i] = Some(1.0);
+
+		for (j, y) in names.iter().enumerate().skip(i + 1) {
+			let (xs, ys) = feature::pair(df, x, y)?;
+			let r = method.correlation(&xs, &ys);
+
+			matrix[i][j] = r;
+			matrix[j][i] = r;
+		}
+	}
+
+	Ok(matrix)
+}
+
+/// order of the features that puts the correlated ones next to each other,
+/// the leaves of an average linkage clustering on `1 - |r|`
+pub fn cluster_order(matrix: &[Vec<Option<f64>>]) -> Vec<usize> {
+	let distance = |i: usize, j: usize| 1.0 - matrix[i][j].map(f64::abs).unwrap_or_default();
+
+	let mut clusters = (0..matrix.len()).map(|i| vec![i]).collect::<Vec<_>>();
+
+	while clusters.len() > 1 {
+		let mut closest = (0, 1, f64::INFINITY);
+
+		for a in 0..clusters.len() {
+			for b in a + 1..clusters.len() {
+				let total = clusters[a]
+					.iter()
+					.flat_map(|&i| clusters[b].iter().map(move |&j| (i, j)))
+					.map(|(i, j)| distance(i, j))
+					.sum::<f64>();
+				let average = total / (clusters[a].len() * clusters[b].len()) as f64;
+
+				if average < closest.2 {
+					closest = (a, b, average);
+				}
+			}
+		}
+
+		let (a, b, _) = closest;
+		let merged = clusters.remove(b);
+		clusters[a].extend(merged);
+	}
+
+	clusters.pop().unwrap_or_default()
+}
+
+/// every pair of `names` with the pearson correlation of the rows where both are set,
+/// from the most to the least correlated in absolute value
+pub fn ranked_pairs(df: &DataFrame, names: &[String]) -> PolarsResult<Vec<(String, String, f64)>> {
+	let mut pairs = Vec::new();
+
+	for (i, x) in names.iter().enumerate() {
+		for y in &names[i + 1..] {
+			let (xs, ys) = feature::pair(df, x, y)?;
+
+			if let Some(r) = pearson(&xs, &ys) {
+				pairs.push((x.clone(), y.clone(), r));
+			}
+		}
+	}
+
+	pairs.sort_by(|a, b| b.2.abs().total_cmp(&a.2.abs()));
+
+	Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_pearson() {
+		let x = [1.0, 2.0, 3.0, 4.0];
+
+		assert!((pearson(&x, &[2.0, 4.0, 6.0, 8.0]).unwrap() - 1.0).abs() < 1e-12);
+		assert!((pearson(&x, &[8.0, 6.0, 4.0, 2.0]).unwrap() + 1.0).abs() < 1e-12);
+		assert_eq!(pearson(&x, &[1.0, 1.0, 1.0, 1.0]), None);
+		assert_eq!(pearson(&[1.0], &[1.0]), None);
+	}
+
+	#[test]
+	fn test_spearman() {
+		assert_eq!(ranks(&[10.0, 30.0, 20.0, 20.0]), [1.0, 4.0, 2.5, 2.5]);
+
+		let x = [1.0, 2.0, 3.0, 4.0];
+		assert!((spearman(&x, &[1.0, 8.0, 27.0, 1000.0]).unwrap() - 1.0).abs() < 1e-12);
+	}
+
+	#[test]
+	fn test_cluster_order() {
+		let r = |r: f64| Some(r);
+		// a and c are close, b and d are close
+		let matrix = vec![
+			vec![r(1.0), r(0.1), r(0.9), r(0.0)],
+			vec![r(0.1), r(1.0), r(0.2), r(-0.8)],
+			vec![r(0.9), r(0.2), r(1.0), r(0.1)],
+			vec![r(0.0), r(-0.8), r(0.1), r(1.0)],
+		];
+
+		assert_eq!(cluster_order(&matrix), [0, 2, 1, 3]);
+		assert!(cluster_order(&[]).is_empty());
+	}
+
+	#[test]
+	fn test_ranked_pairs() {
+		let df = DataFrame::new(vec![
+			Series::new("a".into(), &[Some(1.0), Some(2.0), Some(3.0), None]),
+			Series::new("b".into(), &[1.0, 3.0, 2.0, 5.0]),
+			Series::new("c".into(), &[-2.0, -4.0, -6.0, 0.0]),
+		])
+		.unwrap();
+		let names = ["a", "b", "c"].map(String::from);
+
+		let pairs = ranked_pairs(&df, &names).unwrap();
+
+		assert_eq!(
+			pairs
+				.iter()
+				.map(|(x, y, _)| (x.as_str(), y.as_str()))
+				.collect::<Vec<_>>(),
+			[("a", "c"), ("b", "c"), ("a", "b")]
+		);
+		assert!((pairs[0].2 + 1.0).abs() < 1e-12);
+	}
+}
@@ -0,0 +1,234 @@
+use std::{error::Error, path::PathBuf};
+
+use clap::{arg, value_parser};
+use date::Expansion;
+use model::Model;
+use plotly::{
+	common::{ColorScale, ColorScaleElement},
+	HeatMap, Plot,
+};
+
+use visualize::{
+	annotation,
+	args::{self, Args},
+	boundary::{self, Slice},
+	feature::{self, Group},
+	image,
+	layout::{self, Grid},
+	stats, terminal, trace, Backend, Color, Labels, PlotType,
+};
+
+/// share of the range of each feature added on both sides of the points
+const PADDING: f64 = 0.05;
+/// strength of the colors of the regions, the points keep theirs
+const REGION_STRENGTH: f64 = 0.3;
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let matches = args::command("decision_boundary.png")
+		// the model records the date column it was trained with
+		.mut_arg("date", |arg| arg.hide(true))
+		.mut_arg("date-format", |arg| arg.hide(true))
+		.arg(
+			arg!(--model <path>)
+				.help("model to draw the decision boundary of")
+				.value_parser(value_parser!(PathBuf))
+				.default_value("model.csv")
+				.required(false),
+		)
+		.arg(arg!(--x <feature>).help("feature on the horizontal axis"))
+		.arg(arg!(--y <feature>).help("feature on the vertical axis"))
+		.arg(
+			arg!(--resolution <count>)
+				.help("number of points the model is evaluated at on each axis")
+				.value_parser(value_parser!(usize).range(2..))
+				.default_value("200")
+				.required(false),
+		)
+		.get_matches();
+	let args = Args::from_matches(&matches);
+
+	let path = matches
+		.get_one::<PathBuf>("model")
+		.expect("default ensures there is always a value");
+	// the model reports its errors through their debug representation
+	let model = Model::read(path).map_err(|e| format!("{e:?}"))?;
+
+	let mut dataset = load::load(&args.csv)?;
+
+	// the date features the model was trained with
+	if let Some(date) = &model.date {
+		Expansion::new(&date.column, &date.format).apply(&mut dataset)?;
+	}
+
+	let (x, y) = (
+		matches.get_one::<String>("x").expect("x is required"),
+		matches.get_one::<String>("y").expect("y is required"),
+	);
+	let slice = Slice::new(&model, &boundary::features(&dataset), x, y)?;
+
+	let labels = Labels::new(&dataset, &args.label, &args.palette)?;
+	let groups = feature::groups(&dataset, &labels)?;
+
+	let (xs, ys) = feature::pair(&dataset, x, y)?;
+	let (Some(x_range), Some(y_range)) = (range(&xs), range(&ys)) else {
+		return Err(format!("no row has both {x} and {y}").into());
+	};
+
+	let mut classes = model.weights.keys().cloned().collect::<Vec<_>>();
+	classes.sort();
+
+	match args.backend {
+		Backend::Plotly => {
+			let resolution = *matches
+				.get_one::<usize>("resolution")
+				.expect("default ensures there is always a value");
+			let axes = (
+				stats::linspace(x_range.0, x_range.1, resolution),
+				stats::linspace(y_range.0, y_range.1, resolution),
+			);
+
+			plot(
+				&slice,
+				axes,
+				&classes,
+				&groups,
+				&labels,
+				&args,
+				(x.as_str(), y.as_str()),
+			)
+		}
+		Backend::Terminal => {
+			let width = terminal::width();
+			let xs = stats::linspace(x_range.0, x_range.1, width);
+			let ys = stats::linspace(y_range.0, y_range.1, width / 3);
+
+			let regions = slice
+				.grid(&xs, &ys)
+				.into_iter()
+				.rev()
+				.map(|row| {
+					row.iter()
+						.map(|label| labels.color(label).fade(REGION_STRENGTH))
+						.collect()
+				})
+				.collect::<Vec<_>>();
+
+			let mut points = Vec::new();
+			for group in &groups {
+				let (xs, ys) = group.pair(x, y)?;
+
+				points.extend(xs.into_iter().zip(ys).map(|(x, y)| {
+					(
+						group.color,
+						(x - x_range.0) / (x_range.1 - x_range.0),
+						(y - y_range.0) / (y_range.1 - y_range.0),
+					)
+				}));
+			}
+
+			println!("{}\n", terminal::legend(&groups));
+			for line in terminal::regions(&format!("{y} vs {x}"), &regions, &points) {
+				println!("{line}");
+			}
+
+			Ok(())
+		}
+	}
+}
+
+/// lowest and highest value, widened by the padding
+fn range(values: &[f64]) -> Option<(f64, f64)> {
+	let (min, max) = values.iter().fold(None, |range, &x| match range {
+		None => Some((x, x)),
+		Some((min, max)) => Some((f64::min(min, x), f64::max(max, x))),
+	})?;
+
+	let padding = match (max - min) * PADDING {
+		padding if padding > 0.0 => padding,
+		_ => 1.0,
+	};
+
+	Some((min - padding, max + padding))
+}
+
+/// one band of the color scale per class, the class indexes at their center
+fn color_scale(classes: &[String], labels: &Labels) -> ColorScale {
+	let count = classes.len() as f64;
+
+	ColorScale::Vector(
+		classes
+			.iter()
+			.enumerate()
+			.flat_map(|(i, class)| {
+				let Color(r, g, b) = labels.color(class).fade(REGION_STRENGTH);
+				let color = format!("rgb({r}, {g}, {b})");
+
+				[
+					ColorScaleElement(i as f64 / count, color.clone()),
+					ColorScaleElement((i + 1) as f64 / count, color),
+				]
+			})
+			.collect(),
+	)
+}
+
+fn plot(
+	slice: &Slice,
+	(xs, ys): (Vec<f64>, Vec<f64>),
+	classes: &[String],
+	groups: &[Group],
+	labels: &Labels,
+	args: &Args,
+	(x, y): (&str, &str),
+) -> Result<(), Box<dyn Error>> {
+	let mut plot = Plot::new();
+
+	let z = slice
+		.grid(&xs, &ys)
+		.iter()
+		.map(|row| {
+			row.iter()
+				.map(|label| {
+					classes
+						.iter()
+						.position(|class| class == label)
+						.expect("the model predicts one of its classes") as f64
+				})
+				.collect()
+		})
+		.collect::<Vec<Vec<_>>>();
+
+	plot.add_trace(
+		HeatMap::new(xs, ys, z)
+			.color_scale(color_scale(classes, labels))
+			.zmin(-0.5)
+			.zmax(classes.len() as f64 - 0.5)
+			.show_scale(false),
+	);
+
+	for group in groups {
+		let (xs, ys) = group.pair(x, y)?;
+
+		plot.add_trace(trace::scatter(xs, ys, &group.label, group.color, 1));
+	}
+
+	let mut layout = layout::build(PlotType::DecisionBoundary, Grid::square(1), &labels.name);
+	layout.add_annotation(annotation(1, &format!("{y} vs {x}")));
+	plot.set_layout(layout);
+
+	image::write(&plot, &args.output, image::WIDTH, image::HEIGHT)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_range() {
+		assert_eq!(range(&[0.0, 10.0, 5.0]), Some((-0.5, 10.5)));
+		assert_eq!(range(&[3.0]), Some((2.0, 4.0)));
+		assert_eq!(range(&[]), None);
+	}
+}
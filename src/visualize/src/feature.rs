@@ -1,4 +1,6 @@
-use polars::series::Series;
+use polars::prelude::*;
+
+use crate::{Color, Labels};
 
 pub fn parse(series: &Series) -> Option<Vec<f64>> {
 	let Ok(feature) = series.f64() else {
@@ -17,3 +19,77 @@ pub fn parse(series: &Series) -> Option<Vec<f64>> {
 
 	Some(col)
 }
+
+/// rows of one label, with the values of each numeric feature as given by [`parse`]
+pub struct Group {
+	pub label: String,
+	pub color: Color,
+	pub features: Vec<(String, Vec<f64>)>,
+	pub df: DataFrame,
+}
+
+impl Group {
+	pub fn feature(&self, name: &str) -> Option<&[f64]> {
+		self.features
+			.iter()
+			.find(|(feature, _)| feature == name)
+			.map(|(_, values)| values.as_slice())
+	}
+
+	pub fn pair(&self, x: &str, y: &str) -> PolarsResult<(Vec<f64>, Vec<f64>)> {
+		pair(&self.df, x, y)
+	}
+}
+
+/// values of `x` and `y` on the rows where both are set,
+/// unlike [`parse`] zeros are kept so the rows stay aligned
+pub fn pair(df: &DataFrame, x: &str, y: &str) -> PolarsResult<(Vec<f64>, Vec<f64>)> {
+	let (x, y) = (df.column(x)?.f64()?, df.column(y)?.f64()?);
+
+	Ok(x.into_iter()
+		.zip(y.into_iter())
+		.filter_map(|(x, y)| x.zip(y))
+		.unzip())
+}
+
+/// split the dataset by label, sorted by label
+pub fn groups(dataset: &DataFrame, labels: &Labels) -> PolarsResult<Vec<Group>> {
+	let mut groups = dataset
+		.partition_by([labels.name.as_str()], true)?
+		.into_iter()
+		.map(|df_label| {
+			let (label, color) = labels.extract(&df_label)?;
+
+			let features = df_label
+				.get_columns()
+				.iter()
+				.filter(|series| series.name().as_str() != labels.name)
+				.filter_map(|series| Some((series.name().to_string(), parse(series)?)))
+				.collect();
+
+			Ok(Group {
+				label,
+				color,
+				features,
+				df: df_label,
+			})
+		})
+		.collect::<PolarsResult<Vec<_>>>()?;
+
+	groups.sort_by(|a, b| a.label.cmp(&b.label));
+
+	Ok(groups)
+}
+
+/// names of the features of every group, in order of appearance
+pub fn names(groups: &[Group]) -> Vec<String> {
+	let mut names: Vec<String> = Vec::new();
+
+	for (name, _) in groups.iter().flat_map(|group| &group.features) {
+		if !names.contains(name) {
+			names.push(name.clone());
+		}
+	}
+
+	names
+}
@@ -0,0 +1,123 @@
+use std::error::Error;
+
+use clap::{arg, value_parser};
+use plotly::{
+	common::{ColorScale, ColorScalePalette, Font},
+	layout::Annotation,
+	HeatMap, Layout, Plot,
+};
+use polars::prelude::*;
+
+use visualize::{
+	args::{self, Args},
+	correlation::{self, Method},
+	feature, image, terminal, Backend,
+};
+
+/// pixels per feature, on top of the margins and the names
+const CELL_SIZE: usize = 60;
+const MARGIN: usize = 300;
+
+fn main() -> Result<(), Box<dyn Error>> {
+	// a single panel colored by the correlation, not by label
+	let matches = args::command_without("heatmap.png", &["palette", "columns"])
+		.arg(
+			arg!(--method <method>)
+				.help("correlation: pearson or spearman")
+				.value_parser(value_parser!(Method))
+				.default_value("pearson")
+				.required(false),
+		)
+		.arg(arg!(--cluster "order the features by hierarchical clustering"))
+		.get_matches();
+	let args = Args::from_matches(&matches);
+	let method = *matches
+		.get_one::<Method>("method")
+		.expect("default ensures there is always a value");
+
+	let mut dataset = load::load(&args.csv)?;
+
+	args.expand_date(&mut dataset)?;
+
+	let names = features(&dataset, &args.label);
+	let matrix = correlation::matrix(&dataset, &names, method)?;
+
+	let order = if matches.get_flag("cluster") {
+		correlation::cluster_order(&matrix)
+	} else {
+		(0..names.len()).collect()
+	};
+
+	let names = order.iter().map(|&i| names[i].clone()).collect::<Vec<_>>();
+	let matrix = order
+		.iter()
+		.map(|&i| order.iter().map(|&j| matrix[i][j]).collect())
+		.collect::<Vec<Vec<_>>>();
+
+	match args.backend {
+		Backend::Plotly => plot(&names, &matrix, method, &args),
+		Backend::Terminal => {
+			print!("{}", terminal::heatmap(&names, &matrix));
+			Ok(())
+		}
+	}
+}
+
+/// numeric features, the label column left out even when it is numeric
+fn features(dataset: &DataFrame, label: &str) -> Vec<String> {
+	dataset
+		.get_columns()
+		.iter()
+		.filter(|series| series.name().as_str() != label)
+		.filter(|series| feature::parse(series).is_some())
+		.map(|series| series.name().to_string())
+		.collect()
+}
+
+fn plot(
+	names: &[String],
+	matrix: &[Vec<Option<f64>>],
+	method: Method,
+	args: &Args,
+) -> Result<(), Box<dyn Error>> {
+	let mut plot = Plot::new();
+
+	// rows are drawn from the bottom, reversed so the first feature is on top
+	let y = names.iter().rev().cloned().collect::<Vec<_>>();
+	let z = matrix.iter().rev().cloned().collect::<Vec<_>>();
+
+	plot.add_trace(
+		HeatMap::new(names.to_vec(), y, z)
+			.color_scale(ColorScale::Palette(ColorScalePalette::RdBu))
+			.reverse_scale(true)
+			.zmin(-1.0)
+			.zmax(1.0)
+			.zmid(0.0),
+	);
+
+	let mut layout = Layout::new().title(format!("{method} correlation"));
+
+	for (row, name_y) in matrix.iter().zip(names) {
+		for (r, name_x) in row.iter().zip(names) {
+			let Some(r) = r else {
+				continue;
+			};
+
+			layout.add_annotation(
+				Annotation::new()
+					.x(name_x.clone())
+					.y(name_y.clone())
+					.text(format!("{r:.2}"))
+					.font(Font::new().size(10))
+					.show_arrow(false),
+			);
+		}
+	}
+
+	plot.set_layout(layout);
+
+	let size = MARGIN + CELL_SIZE * names.len();
+	image::write(&plot, &args.output, size, size)?;
+
+	Ok(())
+}
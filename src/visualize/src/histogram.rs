@@ -1,60 +1,240 @@
-use std::{error::Error, path::Path};
+use std::error::Error;
 
-use plotly::Plot;
-use polars::prelude::*;
+use analyze::histogram::sturges;
+use clap::{arg, parser::ValueSource, value_parser, ArgMatches};
+use plotly::{histogram::Bins, Plot};
 
 use visualize::{
-	annotation, args, feature, image, layout, populate, trace, Label, PlotType, LABEL_NAME,
+	annotation,
+	args::{self, Args},
+	bars::{BarMode, Norm},
+	feature::{self, Group},
+	image,
+	layout::{self, Grid},
+	stats, terminal, trace, Backend, Labels, PlotType,
 };
 
+const TERMINAL_COLUMNS: usize = 3;
+
+/// points the density curves are evaluated at
+const KDE_POINTS: usize = 100;
+
+/// options of the bars, the sparklines of the terminal have none of them
+const PLOTLY_OPTIONS: [&str; 5] = ["bins", "histnorm", "barmode", "opacity", "kde"];
+
+struct Options {
+	bins: Option<usize>,
+	norm: Norm,
+	bar_mode: BarMode,
+	opacity: f64,
+	kde: bool,
+}
+
+impl Options {
+	fn from_matches(matches: &ArgMatches) -> Self {
+		Options {
+			bins: matches.get_one::<usize>("bins").copied(),
+			norm: *matches
+				.get_one::<Norm>("histnorm")
+				.expect("default ensures there is always a value"),
+			bar_mode: *matches
+				.get_one::<BarMode>("barmode")
+				.expect("default ensures there is always a value"),
+			opacity: *matches
+				.get_one::<f64>("opacity")
+				.expect("default ensures there is always a value"),
+			kde: matches.get_flag("kde"),
+		}
+	}
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
-	let args = args::parse("histogram.png");
+	let matches = args::command("histogram.png")
+		.arg(
+			arg!(--bins <count>)
+				.help(
+					"number of bins of every feature, shared by the labels (automatic by default)",
+				)
+				.value_parser(value_parser!(usize).range(1..))
+				.required(false),
+		)
+		.arg(
+			arg!(--histnorm <norm>)
+				.help("height of the bars: count, percent or density")
+				.value_parser(value_parser!(Norm))
+				.default_value("count")
+				.required(false),
+		)
+		.arg(
+			arg!(--barmode <mode>)
+				.help("bars of the labels: overlay, stack or group")
+				.value_parser(value_parser!(BarMode))
+				.default_value("overlay")
+				.required(false),
+		)
+		.arg(
+			arg!(--opacity <opacity>)
+				.help("opacity of the bars, from 0 to 1")
+				.value_parser(opacity)
+				.default_value("0.75")
+				.required(false),
+		)
+		.arg(arg!(--kde "draw the kernel density estimate of each label over its bars"))
+		.get_matches();
+	let args = Args::from_matches(&matches);
+	let options = Options::from_matches(&matches);
+
+	if args.backend == Backend::Terminal {
+		if let Some(option) = PLOTLY_OPTIONS
+			.iter()
+			.find(|id| matches.value_source(id) == Some(ValueSource::CommandLine))
+		{
+			return Err(format!("--{option} only applies to the plotly backend").into());
+		}
+	}
 
-	let mut dataset = load::load(args.csv)?;
+	let mut dataset = load::load(&args.csv)?;
 
-	populate::date(&mut dataset)?;
+	args.expand_date(&mut dataset)?;
 
-	plot(dataset, args.output)
+	let labels = Labels::new(&dataset, &args.label, &args.palette)?;
+	let groups = feature::groups(&dataset, &labels)?;
+
+	match args.backend {
+		Backend::Plotly => plot(&groups, &labels, &args, &options),
+		Backend::Terminal => {
+			print(&groups, args.columns.unwrap_or(TERMINAL_COLUMNS));
+			Ok(())
+		}
+	}
 }
 
-fn plot<P: AsRef<Path>>(dataset: DataFrame, output: P) -> Result<(), Box<dyn Error>> {
+fn plot(
+	groups: &[Group],
+	labels: &Labels,
+	args: &Args,
+	options: &Options,
+) -> Result<(), Box<dyn Error>> {
 	let mut plot = Plot::new();
 
-	let mut layout = layout::build(PlotType::Histogram, 4);
+	let names = feature::names(groups);
 
-	for (i, df_label) in dataset
-		.partition_by([LABEL_NAME], true)?
-		.into_iter()
-		.enumerate()
-	{
-		let mut plot_index = 1;
+	let grid = Grid::new(names.len(), args.columns);
+	let mut layout =
+		layout::build(PlotType::Histogram, grid, &labels.name).bar_mode(options.bar_mode.plotly());
 
-		let (label, color) = Label::extract(&df_label)?;
+	for (i, name) in names.iter().enumerate() {
+		let plot_index = i + 1;
 
-		for series in df_label.get_columns() {
-			let Some(col) = feature::parse(series) else {
-				continue;
-			};
+		let series = groups
+			.iter()
+			.filter_map(|group| Some((group, group.feature(name)?)))
+			.collect::<Vec<_>>();
+		let bins = bins(series.iter().flat_map(|(_, col)| col.iter()), options);
 
-			plot.add_trace(trace::histogram(col, &label, color, plot_index));
+		for (group, col) in &series {
+			let mut trace = trace::histogram(col.to_vec(), &group.label, group.color, plot_index)
+				.hist_norm(options.norm.plotly())
+				.opacity(options.opacity);
+			if let Some((start, end, size)) = bins {
+				trace = trace.x_bins(Bins::new(start, end, size));
+			}
+			plot.add_trace(trace);
 
-			if i == 0 {
-				layout.add_annotation(annotation(plot_index, series.name()));
+			if !options.kde {
+				continue;
 			}
 
-			plot_index += 1;
+			let mut sorted = col.to_vec();
+			sorted.sort_by(f64::total_cmp);
+
+			let (Some(bandwidth), Some((start, end, size))) = (stats::bandwidth(&sorted), bins)
+			else {
+				continue;
+			};
+
+			let x = stats::linspace(start, end, KDE_POINTS);
+			let scale = options.norm.scale(sorted.len(), size);
+			let y = stats::kde(&sorted, bandwidth, &x)
+				.into_iter()
+				.map(|density| density * scale)
+				.collect();
+
+			plot.add_trace(trace::line(x, y, group.color, plot_index));
 		}
+
+		layout.add_annotation(annotation(plot_index, name));
 	}
 
 	plot.set_layout(layout);
 
-	plot.write_image(
-		output,
-		image::FORMAT,
-		image::WIDTH,
-		image::HEIGHT,
-		image::SCALE,
-	);
+	let (width, height) = grid.image_size();
+	image::write(&plot, &args.output, width, height)?;
 
 	Ok(())
 }
+
+fn opacity(s: &str) -> Result<f64, String> {
+	match s.parse::<f64>() {
+		Ok(opacity) if (0.0..=1.0).contains(&opacity) => Ok(opacity),
+		Ok(opacity) => Err(format!("{opacity} is not an opacity, from 0 to 1")),
+		Err(e) => Err(e.to_string()),
+	}
+}
+
+/// start, end and width of bins shared by every label, `None` to let plotly choose,
+/// the density curves need them to be scaled like the bars
+fn bins<'v>(values: impl Iterator<Item = &'v f64>, options: &Options) -> Option<(f64, f64, f64)> {
+	if options.bins.is_none() && !options.kde {
+		return None;
+	}
+
+	let (count, min, max) = values.fold(
+		(0, f64::INFINITY, f64::NEG_INFINITY),
+		|(n, min, max), &x| (n + 1, min.min(x), max.max(x)),
+	);
+	if count == 0 {
+		return None;
+	}
+
+	let bins = options.bins.unwrap_or_else(|| sturges(count));
+	let size = match (max - min) / bins as f64 {
+		size if size > 0.0 => size,
+		_ => 1.0,
+	};
+
+	Some((min, min + size * bins as f64, size))
+}
+
+fn print(groups: &[Group], columns: usize) {
+	let width = terminal::panel_width(columns);
+
+	let panels = feature::names(groups)
+		.iter()
+		.map(|name| {
+			let series = groups
+				.iter()
+				.filter_map(|group| Some((group.color, group.feature(name)?)))
+				.collect::<Vec<_>>();
+
+			terminal::histogram(name, &series, width)
+		})
+		.collect::<Vec<_>>();
+
+	println!("{}\n", terminal::legend(groups));
+	print!("{}", terminal::grid(&panels, width, columns));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_opacity() {
+		assert_eq!(opacity("0.5"), Ok(0.5));
+		assert_eq!(opacity("1"), Ok(1.0));
+		assert!(opacity("1.5").is_err());
+		assert!(opacity("-0.1").is_err());
+		assert!(opacity("opaque").is_err());
+	}
+}
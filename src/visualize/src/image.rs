@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use plotly::{ImageFormat, Plot};
+
+pub const WIDTH: usize = 1200;
+pub const HEIGHT: usize = 1200;
+pub const SCALE: f64 = 1.0;
+
+/// how a plot is written, chosen from the extension of its path
+#[derive(Debug, PartialEq)]
+pub enum Output {
+	/// rendered by kaleido
+	Image(ImageFormat),
+	/// interactive page, no kaleido needed, plotly 0.10 cannot embed plotly.js
+	/// so the page loads it from its cdn
+	Html,
+}
+
+impl Output {
+	pub fn from_path(path: &Path) -> Result<Self, String> {
+		let extension = path
+			.extension()
+			.map(|extension| extension.to_string_lossy().to_lowercase())
+			.unwrap_or_default();
+
+		match extension.as_str() {
+			"png" => Ok(Output::Image(ImageFormat::PNG)),
+			"jpg" | "jpeg" => Ok(Output::Image(ImageFormat::JPEG)),
+			"webp" => Ok(Output::Image(ImageFormat::WEBP)),
+			"svg" => Ok(Output::Image(ImageFormat::SVG)),
+			"pdf" => Ok(Output::Image(ImageFormat::PDF)),
+			"html" | "htm" => Ok(Output::Html),
+			_ => Err(format!(
+				"cannot write a plot to {}: expected a png, jpg, webp, svg, pdf or html extension",
+				path.display()
+			)),
+		}
+	}
+}
+
+/// write `plot` to `path` in the format of its extension
+pub fn write(plot: &Plot, path: &Path, width: usize, height: usize) -> Result<(), String> {
+	match Output::from_path(path)? {
+		Output::Html => {
+			plot.write_html(path);
+			Ok(())
+		}
+		Output::Image(format) => write_image(plot, path, format, width, height),
+	}
+}
+
+#[cfg(feature = "kaleido")]
+fn write_image(
+	plot: &Plot,
+	path: &Path,
+	format: ImageFormat,
+	width: usize,
+	height: usize,
+) -> Result<(), String> {
+	plot.write_image(path, format, width, height, SCALE);
+	Ok(())
+}
+
+#[cfg(not(feature = "kaleido"))]
+fn write_image(
+	_plot: &Plot,
+	path: &Path,
+	_format: ImageFormat,
+	_width: usize,
+	_height: usize,
+) -> Result<(), String> {
+	Err(format!(
+		"cannot write {}: built without the kaleido feature, only html is available",
+		path.display()
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_output_from_path() {
+		assert_eq!(
+			Output::from_path(Path::new("pair_plot.PNG")),
+			Ok(Output::Image(ImageFormat::PNG))
+		);
+		assert_eq!(
+			Output::from_path(Path::new("a/b.jpeg")),
+			Ok(Output::Image(ImageFormat::JPEG))
+		);
+		assert_eq!(
+			Output::from_path(Path::new("histogram.html")),
+			Ok(Output::Html)
+		);
+		assert!(Output::from_path(Path::new("histogram")).is_err());
+		assert!(Output::from_path(Path::new("histogram.gif")).is_err());
+	}
+}
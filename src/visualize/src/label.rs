@@ -1,59 +1,236 @@
-use plotly::color::NamedColor;
-use polars::frame::DataFrame;
+use std::collections::BTreeMap;
 
-use super::LABEL_NAME;
+use plotly::color::Rgb;
+use polars::prelude::*;
 
-pub enum Label {
-	Ravenclaw,
-	Slytherin,
-	Hufflepuff,
-	Gryffindor,
+/// label column of the Hogwarts dataset, used when `--label` is not given
+pub const DEFAULT_LABEL: &str = "Hogwarts House";
+
+/// label of the rows with a null label
+const NULL_LABEL: &str = "null";
+
+const UNKNOWN_COLOR: Color = Color(128, 128, 128);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+	pub fn rgb(self) -> Rgb {
+		Rgb::new(self.0, self.1, self.2)
+	}
+
+	/// blend toward white, `strength` 1 keeps the color and 0 gives white
+	pub fn fade(self, strength: f64) -> Color {
+		let fade = |c: u8| (255.0 - (255.0 - c as f64) * strength).round() as u8;
+
+		Color(fade(self.0), fade(self.1), fade(self.2))
+	}
+}
+
+/// category10 colors, cycled through when there are more labels
+const CATEGORY: [Color; 10] = [
+	Color(31, 119, 180),
+	Color(255, 127, 14),
+	Color(44, 160, 44),
+	Color(214, 39, 40),
+	Color(148, 103, 189),
+	Color(140, 86, 75),
+	Color(227, 119, 194),
+	Color(127, 127, 127),
+	Color(188, 189, 34),
+	Color(23, 190, 207),
+];
+
+const HOUSES: [(&str, Color); 4] = [
+	("Ravenclaw", Color(0, 0, 255)),
+	("Slytherin", Color(0, 128, 0)),
+	("Hufflepuff", Color(255, 165, 0)),
+	("Gryffindor", Color(255, 0, 0)),
+];
+
+/// colors given to the labels, in sorted order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Palette {
+	/// the color of each Hogwarts house, category10 for any other label
+	#[default]
+	Houses,
+	Category,
+	Custom(Vec<Color>),
 }
 
-impl std::fmt::Display for Label {
+impl std::fmt::Display for Palette {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		match *self {
-			Label::Ravenclaw => write!(f, "Ravenclaw"),
-			Label::Slytherin => write!(f, "Slytherin"),
-			Label::Hufflepuff => write!(f, "Hufflepuff"),
-			Label::Gryffindor => write!(f, "Gryffindor"),
+			Palette::Houses => write!(f, "houses"),
+			Palette::Category => write!(f, "category"),
+			Palette::Custom(ref colors) => write!(
+				f,
+				"{}",
+				colors
+					.iter()
+					.map(|c| format!("#{:02x}{:02x}{:02x}", c.0, c.1, c.2))
+					.collect::<Vec<_>>()
+					.join(",")
+			),
 		}
 	}
 }
 
-impl std::str::FromStr for Label {
+impl std::str::FromStr for Palette {
 	type Err = String;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		match s {
-			"Ravenclaw" => Ok(Label::Ravenclaw),
-			"Slytherin" => Ok(Label::Slytherin),
-			"Hufflepuff" => Ok(Label::Hufflepuff),
-			"Gryffindor" => Ok(Label::Gryffindor),
-			_ => Err(format!("{s} is not a valid {LABEL_NAME}")),
+			"houses" => Ok(Palette::Houses),
+			"category" => Ok(Palette::Category),
+			_ => s
+				.split(',')
+				.map(parse_hex)
+				.collect::<Result<Vec<_>, _>>()
+				.map(Palette::Custom),
 		}
 	}
 }
 
-impl Label {
-	fn color(&self) -> NamedColor {
-		match self {
-			Label::Ravenclaw => NamedColor::Blue,
-			Label::Slytherin => NamedColor::Green,
-			Label::Hufflepuff => NamedColor::Orange,
-			Label::Gryffindor => NamedColor::Red,
-		}
+fn parse_hex(s: &str) -> Result<Color, String> {
+	let error =
+		|| format!("{s} is not a palette (houses, category or comma separated #rrggbb colors)");
+
+	let hex = s.trim().strip_prefix('#').ok_or_else(error)?;
+	if hex.len() != 6 || !hex.is_ascii() {
+		return Err(error());
+	}
+
+	let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| error());
+
+	Ok(Color(channel(0)?, channel(2)?, channel(4)?))
+}
+
+impl Palette {
+	fn colors(&self, labels: &[String]) -> Vec<Color> {
+		let cycle: &[Color] = match self {
+			Palette::Custom(colors) if !colors.is_empty() => colors,
+			_ => &CATEGORY,
+		};
+
+		labels
+			.iter()
+			.enumerate()
+			.map(|(i, label)| {
+				let house = HOUSES.iter().find(|(house, _)| house == label);
+
+				match (self, house) {
+					(Palette::Houses, Some((_, color))) => *color,
+					_ => cycle[i % cycle.len()],
+				}
+			})
+			.collect()
+	}
+}
+
+/// every label of a dataset and its color
+pub struct Labels {
+	pub name: String,
+	colors: BTreeMap<String, Color>,
+}
+
+impl Labels {
+	pub fn new(df: &DataFrame, name: &str, palette: &Palette) -> PolarsResult<Self> {
+		let mut labels = values(df.column(name)?)?
+			.into_iter()
+			.flatten()
+			.collect::<Vec<_>>();
+		labels.sort();
+		labels.dedup();
+
+		let colors = palette.colors(&labels);
+
+		Ok(Self {
+			name: name.to_owned(),
+			colors: labels.into_iter().zip(colors).collect(),
+		})
 	}
 
-	pub fn extract(df: &DataFrame) -> Result<(String, NamedColor), String> {
-		let label = df
-			.column(LABEL_NAME)
-			.expect("Label column not found")
+	/// sorted labels
+	pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+		self.colors
 			.iter()
+			.map(|(label, color)| (label.as_str(), *color))
+	}
+
+	pub fn color(&self, label: &str) -> Color {
+		self.colors.get(label).copied().unwrap_or(UNKNOWN_COLOR)
+	}
+
+	/// label and color of a DataFrame partitioned by label
+	pub fn extract(&self, df: &DataFrame) -> PolarsResult<(String, Color)> {
+		let label = values(df.column(&self.name)?)?
+			.into_iter()
 			.next()
-			.expect("Series is empty");
-		let label: Label = label.get_str().unwrap_or("").parse()?;
+			.flatten()
+			.unwrap_or_else(|| NULL_LABEL.to_owned());
+
+		let color = self.color(&label);
+
+		Ok((label, color))
+	}
+}
+
+/// values of a label column of any type
+fn values(series: &Series) -> PolarsResult<Vec<Option<String>>> {
+	Ok(series
+		.cast(&DataType::String)?
+		.str()?
+		.into_iter()
+		.map(|label| label.map(str::to_owned))
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_palette_parse() {
+		assert_eq!("houses".parse(), Ok(Palette::Houses));
+		assert_eq!(
+			"#ff0000,#00Ff80".parse(),
+			Ok(Palette::Custom(vec![Color(255, 0, 0), Color(0, 255, 128)]))
+		);
+		assert!("#ff00".parse::<Palette>().is_err());
+		assert!("red".parse::<Palette>().is_err());
+	}
+
+	#[test]
+	fn test_labels() {
+		let df = DataFrame::new(vec![Series::new(
+			"species".into(),
+			&[Some("b"), Some("Slytherin"), None, Some("b")],
+		)])
+		.unwrap();
+
+		let labels = Labels::new(&df, "species", &Palette::Houses).unwrap();
+
+		assert_eq!(
+			labels.iter().collect::<Vec<_>>(),
+			[("Slytherin", HOUSES[1].1), ("b", CATEGORY[1])]
+		);
+		assert_eq!(labels.color("c"), UNKNOWN_COLOR);
+
+		let labels = Labels::new(&df, "species", &Palette::Category).unwrap();
+
+		assert_eq!(labels.color("Slytherin"), CATEGORY[0]);
+	}
+
+	#[test]
+	fn test_labels_numeric() {
+		let df = DataFrame::new(vec![Series::new("digit".into(), &[3, 1, 3])]).unwrap();
+
+		let labels = Labels::new(&df, "digit", &Palette::Category).unwrap();
 
-		Ok((label.to_string(), label.color()))
+		assert_eq!(
+			labels.extract(&df).unwrap(),
+			(String::from("3"), CATEGORY[1])
+		);
 	}
 }
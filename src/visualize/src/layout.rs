@@ -1,27 +1,128 @@
-use crate::PlotType;
+use crate::{image, PlotType};
 
-use super::LABEL_NAME;
 use plotly::{
 	color::NamedColor,
 	layout::{GridPattern, ItemSizing, LayoutGrid, Legend},
 	Layout,
 };
 
-pub fn build(plot_type: PlotType, size: usize) -> Layout {
+/// width over height the grids aim for
+const ASPECT_RATIO: f64 = 4.0 / 3.0;
+
+const CELL_WIDTH: usize = image::WIDTH / 4;
+const CELL_HEIGHT: usize = image::HEIGHT / 4;
+
+/// rows and columns of subplots
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid {
+	pub rows: usize,
+	pub columns: usize,
+}
+
+impl Grid {
+	/// enough cells for `count` subplots, `columns` wide
+	/// or as close to the aspect ratio as possible
+	pub fn new(count: usize, columns: Option<usize>) -> Self {
+		let count = count.max(1);
+
+		let columns = columns
+			.unwrap_or_else(|| (count as f64 * ASPECT_RATIO).sqrt().ceil() as usize)
+			.clamp(1, count);
+
+		Self {
+			rows: count.div_ceil(columns),
+			columns,
+		}
+	}
+
+	pub fn square(size: usize) -> Self {
+		Self {
+			rows: size.max(1),
+			columns: size.max(1),
+		}
+	}
+
+	/// width and height of an image with cells of a constant size
+	pub fn image_size(&self) -> (usize, usize) {
+		(CELL_WIDTH * self.columns, CELL_HEIGHT * self.rows)
+	}
+}
+
+pub fn build(plot_type: PlotType, grid: Grid, label: &str) -> Layout {
 	Layout::new()
 		.grid(
 			LayoutGrid::new()
-				.rows(size)
-				.columns(size)
+				.rows(grid.rows)
+				.columns(grid.columns)
 				.pattern(GridPattern::Independent),
 		)
 		.legend(
 			Legend::new()
-				.title(LABEL_NAME)
+				.title(label)
 				.item_sizing(ItemSizing::Constant)
 				.border_color(NamedColor::Black)
 				.border_width(1)
 				.background_color(NamedColor::GhostWhite),
 		)
-		.title(format!("{plot_type} plot of {LABEL_NAME}"))
+		.title(format!("{plot_type} plot of {label}"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_grid() {
+		assert_eq!(
+			Grid::new(13, None),
+			Grid {
+				rows: 3,
+				columns: 5
+			}
+		);
+		assert_eq!(
+			Grid::new(16, None),
+			Grid {
+				rows: 4,
+				columns: 5
+			}
+		);
+		assert_eq!(
+			Grid::new(3, None),
+			Grid {
+				rows: 2,
+				columns: 2
+			}
+		);
+		assert_eq!(
+			Grid::new(0, None),
+			Grid {
+				rows: 1,
+				columns: 1
+			}
+		);
+	}
+
+	#[test]
+	fn test_grid_columns() {
+		assert_eq!(
+			Grid::new(13, Some(2)),
+			Grid {
+				rows: 7,
+				columns: 2
+			}
+		);
+		assert_eq!(
+			Grid::new(3, Some(10)),
+			Grid {
+				rows: 1,
+				columns: 3
+			}
+		);
+	}
+
+	#[test]
+	fn test_image_size() {
+		assert_eq!(Grid::new(6, Some(3)).image_size(), (900, 600));
+	}
 }
@@ -0,0 +1,210 @@
+use std::error::Error;
+
+use plotly::Plot;
+use polars::prelude::*;
+
+use visualize::{
+	annotation,
+	args::{self, Args},
+	image,
+	layout::{self, Grid},
+	terminal, trace, Backend, Color, Labels, PlotType,
+};
+
+/// columns of the log written by `train --log`
+const ITERATION: &str = "iteration";
+const CLASS: &str = "class";
+const SET: &str = "set";
+const LOSS: &str = "loss";
+const ACCURACY: &str = "accuracy";
+
+const METRICS: [&str; 2] = [LOSS, ACCURACY];
+/// the testing curves are dashed
+const TESTING: &str = "test";
+
+const TERMINAL_COLUMNS: usize = 2;
+
+/// metrics of one class on one set at each iteration
+struct Curve {
+	class: String,
+	color: Color,
+	set: String,
+	iterations: Vec<f64>,
+	loss: Vec<f64>,
+	accuracy: Vec<f64>,
+}
+
+impl Curve {
+	fn metric(&self, metric: &str) -> &[f64] {
+		match metric {
+			LOSS => &self.loss,
+			_ => &self.accuracy,
+		}
+	}
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+	// the classes of the log are its labels, and it has no dates
+	let matches = args::command_without("learning_curve.png", &["label", "date", "date-format"])
+		.mut_arg("csv", |arg| {
+			arg.help("training log csv path, as written by `train --log`")
+				.default_value("log.csv")
+		})
+		.get_matches();
+	let args = Args::from_matches(&matches);
+
+	let log = load::load(&args.csv)?;
+
+	let labels = Labels::new(&log, CLASS, &args.palette)?;
+	let curves = curves(&log, &labels)?;
+
+	match args.backend {
+		Backend::Plotly => plot(&curves, &args),
+		Backend::Terminal => {
+			print(&curves, args.columns.unwrap_or(TERMINAL_COLUMNS));
+			Ok(())
+		}
+	}
+}
+
+/// curves sorted by class then set
+fn curves(log: &DataFrame, labels: &Labels) -> PolarsResult<Vec<Curve>> {
+	let values = |df: &DataFrame, name: &str| -> PolarsResult<Vec<f64>> {
+		Ok(df
+			.column(name)?
+			.cast(&DataType::Float64)?
+			.f64()?
+			.into_iter()
+			.flatten()
+			.collect())
+	};
+
+	let mut curves = log
+		.partition_by([CLASS, SET], true)?
+		.into_iter()
+		.map(|df| {
+			let (class, color) = labels.extract(&df)?;
+			let set = df.column(SET)?.str()?.get(0).unwrap_or_default().to_owned();
+
+			Ok(Curve {
+				class,
+				color,
+				set,
+				iterations: values(&df, ITERATION)?,
+				loss: values(&df, LOSS)?,
+				accuracy: values(&df, ACCURACY)?,
+			})
+		})
+		.collect::<PolarsResult<Vec<_>>>()?;
+
+	curves.sort_by(|a, b| (&a.class, &a.set).cmp(&(&b.class, &b.set)));
+
+	Ok(curves)
+}
+
+fn plot(curves: &[Curve], args: &Args) -> Result<(), Box<dyn Error>> {
+	let mut plot = Plot::new();
+
+	let grid = Grid::new(METRICS.len(), args.columns);
+	let mut layout = layout::build(PlotType::LearningCurve, grid, CLASS);
+
+	for (i, metric) in METRICS.iter().enumerate() {
+		let plot_index = i + 1;
+
+		for curve in curves {
+			plot.add_trace(trace::curve(
+				curve.iterations.clone(),
+				curve.metric(metric).to_vec(),
+				&format!("{} ({})", curve.class, curve.set),
+				curve.color,
+				curve.set == TESTING,
+				plot_index,
+			));
+		}
+
+		layout.add_annotation(annotation(plot_index, metric));
+	}
+
+	plot.set_layout(layout);
+
+	let (width, height) = grid.image_size();
+	image::write(&plot, &args.output, width, height)?;
+
+	Ok(())
+}
+
+/// a panel for each metric on each set
+fn print(curves: &[Curve], columns: usize) {
+	let width = terminal::panel_width(columns);
+
+	let mut sets = curves
+		.iter()
+		.map(|curve| curve.set.as_str())
+		.collect::<Vec<_>>();
+	sets.sort();
+	sets.dedup();
+	// training first
+	sets.reverse();
+
+	let mut panels = Vec::new();
+	for metric in METRICS {
+		for set in &sets {
+			let series = curves
+				.iter()
+				.filter(|curve| curve.set == *set)
+				.map(|curve| {
+					(
+						curve.color,
+						curve.iterations.as_slice(),
+						curve.metric(metric),
+					)
+				})
+				.collect::<Vec<_>>();
+
+			panels.push(terminal::scatter(
+				&format!("{metric} ({set})"),
+				&series,
+				width,
+				width / 3,
+			));
+		}
+	}
+
+	let legend = curves
+		.iter()
+		.filter(|curve| curve.set != TESTING)
+		.map(|curve| format!("{} {}", terminal::paint("●", curve.color), curve.class))
+		.collect::<Vec<_>>()
+		.join("  ");
+
+	println!("{legend}\n");
+	print!("{}", terminal::grid(&panels, width, columns));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use visualize::Palette;
+
+	#[test]
+	fn test_curves() {
+		let log = DataFrame::new(vec![
+			Series::new(ITERATION.into(), &[0, 0, 1, 1]),
+			Series::new(CLASS.into(), &["b", "a", "b", "a"]),
+			Series::new(SET.into(), &["train"; 4]),
+			Series::new(LOSS.into(), &[0.7, 0.6, 0.5, 0.4]),
+			Series::new(ACCURACY.into(), &[0.0, 0.1, 0.8, 0.9]),
+		])
+		.unwrap();
+
+		let labels = Labels::new(&log, CLASS, &Palette::Category).unwrap();
+		let curves = curves(&log, &labels).unwrap();
+
+		assert_eq!(curves.len(), 2);
+		assert_eq!(curves[0].class, "a");
+		assert_eq!(curves[0].iterations, [0.0, 1.0]);
+		assert_eq!(curves[0].metric(LOSS), [0.6, 0.4]);
+		assert_eq!(curves[1].metric(ACCURACY), [0.0, 0.8]);
+	}
+}
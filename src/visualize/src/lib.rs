@@ -1,23 +1,23 @@
 pub mod args;
+pub mod bars;
+pub mod boundary;
+pub mod confusion;
+pub mod correlation;
 pub mod feature;
+pub mod image;
 pub mod layout;
-pub mod populate;
+pub mod stats;
+pub mod terminal;
 pub mod trace;
 
 mod annotation;
 pub use annotation::annotation;
 
+mod backend;
+pub use backend::Backend;
+
 mod label;
-pub use label::Label;
+pub use label::{Color, Labels, Palette, DEFAULT_LABEL};
 
 mod plot_type;
 pub use plot_type::PlotType;
-
-pub const LABEL_NAME: &str = "Hogwarts House";
-
-pub mod image {
-	pub const FORMAT: plotly::ImageFormat = plotly::ImageFormat::PNG;
-	pub const WIDTH: usize = 1200;
-	pub const HEIGHT: usize = 1200;
-	pub const SCALE: f64 = 1.0;
-}
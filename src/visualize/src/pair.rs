@@ -1,10 +1,23 @@
-use std::{error::Error, path::PathBuf};
+use std::{
+	error::Error,
+	path::{Path, PathBuf},
+};
 
+use clap::arg;
 use plotly::Plot;
-use polars::prelude::*;
+use polars::prelude::PolarsResult;
+use select::Selection;
 
-use visualize::{annotation, args, feature, image, layout, trace, Label, PlotType, LABEL_NAME};
+use visualize::{
+	annotation,
+	args::{self, Args},
+	feature::{self, Group},
+	image,
+	layout::{self, Grid},
+	terminal, trace, Backend, Labels, PlotType,
+};
 
+/// pair plotted by default when the dataset has all of them
 const USEFUL_FEATURES: [&str; 6] = [
 	"Divination",
 	"Ancient Runes",
@@ -13,108 +26,260 @@ const USEFUL_FEATURES: [&str; 6] = [
 	"Charms",
 	"Flying",
 ];
-const EXPECTED_FEATURES_LEN: usize = 13;
+const ALL_FEATURES: &str = "all";
 
 fn main() -> Result<(), Box<dyn Error>> {
-	let args = args::parse("pair_plot.png");
+	let matches = args::command("pair_plot.png")
+		.arg(
+			arg!(--features <features>)
+				.help("comma separated numeric features or globs to pair, or all (default: the useful Hogwarts courses, or all when the dataset lacks them)")
+				.value_delimiter(',')
+				.required(false),
+		)
+		.arg(arg!(--complete "also write <output>_complete with every numeric feature"))
+		.get_matches();
+	let args = Args::from_matches(&matches);
 
-	let dataset = load::load(args.csv)?;
+	let mut dataset = load::load(&args.csv)?;
 
-	plot(&dataset, args.output.clone(), false)?;
-	plot(&dataset, args.output, true)?;
+	args.expand_date(&mut dataset)?;
+
+	let labels = Labels::new(&dataset, &args.label, &args.palette)?;
+	let groups = feature::groups(&dataset, &labels)?;
+
+	let all = feature::names(&groups);
+	let features = features(
+		&all,
+		matches
+			.get_many::<String>("features")
+			.map(|features| features.cloned().collect()),
+	)?;
+
+	if args.backend == Backend::Terminal {
+		print(&groups, &features);
+		return Ok(());
+	}
+
+	plot(&groups, &labels, &features, &args.output)?;
+	if matches.get_flag("complete") {
+		plot(&groups, &labels, &all, &complete_output(&args.output))?;
+	}
 
 	Ok(())
 }
 
-fn plot(dataset: &DataFrame, output: PathBuf, all_features: bool) -> Result<(), Box<dyn Error>> {
-	let mut plot = Plot::new();
-
-	let size = if all_features {
-		EXPECTED_FEATURES_LEN
-	} else {
-		USEFUL_FEATURES.len()
-	};
-	let mut layout = layout::build(PlotType::Pair, size);
+/// the features matching the requested names or globs, all of them with `all`
+fn features(all: &[String], requested: Option<Vec<String>>) -> PolarsResult<Vec<String>> {
+	match requested {
+		Some(requested) if requested.iter().any(|name| name == ALL_FEATURES) => Ok(all.to_vec()),
+		Some(requested) => {
+			Selection::new(&requested, &[], false)?.filter(all.iter().map(String::as_str))
+		}
+		None if USEFUL_FEATURES
+			.iter()
+			.all(|useful| all.iter().any(|name| name == useful)) =>
+		{
+			Ok(all
+				.iter()
+				.filter(|name| USEFUL_FEATURES.contains(&name.as_str()))
+				.cloned()
+				.collect())
+		}
+		None => Ok(all.to_vec()),
+	}
+}
 
-	for (i, df_label) in dataset
-		.partition_by([LABEL_NAME], true)?
-		.into_iter()
-		.enumerate()
-	{
-		let mut plot_index = 1;
+fn plot(
+	groups: &[Group],
+	labels: &Labels,
+	features: &[String],
+	output: &Path,
+) -> Result<(), Box<dyn Error>> {
+	let mut plot = Plot::new();
 
-		let (label, color) = Label::extract(&df_label)?;
+	let grid = Grid::square(features.len());
+	let mut layout = layout::build(PlotType::Pair, grid, &labels.name);
 
-		for series_y in df_label.get_columns() {
-			let name_y = series_y.name();
-			if !all_features && !USEFUL_FEATURES.contains(&name_y.as_str()) {
-				continue;
+	for group in groups {
+		for (plot_index, x, y) in panels(group, features) {
+			match x {
+				None => plot.add_trace(trace::histogram(
+					y.to_vec(),
+					&group.label,
+					group.color,
+					plot_index,
+				)),
+				Some(x) => plot.add_trace(trace::scatter(
+					x.to_vec(),
+					y.to_vec(),
+					&group.label,
+					group.color,
+					plot_index,
+				)),
 			}
+		}
+	}
 
-			for series_x in df_label.get_columns() {
-				let name_x = series_x.name();
-				if !all_features && !USEFUL_FEATURES.contains(&name_x.as_str()) {
-					continue;
-				}
-
-				let Some(y) = feature::parse(series_y) else {
-					continue;
-				};
-
-				if name_x == name_y {
-					plot.add_trace(trace::histogram(y, &label, color, plot_index));
-
-					if i == 0 {
-						layout.add_annotation(annotation(plot_index, name_y));
-					}
-				} else {
-					let Some(x) = feature::parse(series_x) else {
-						continue;
-					};
-
-					plot.add_trace(trace::scatter(x, y, &label, color, plot_index));
-
-					if i == 0 {
-						layout.add_annotation(annotation(
-							plot_index,
-							&format!("{name_y} vs {name_x}"),
-						));
-					}
-				}
-
-				plot_index += 1;
-			}
+	for (plot_index, name_x, name_y) in cells(features) {
+		if name_x == name_y {
+			layout.add_annotation(annotation(plot_index, name_y));
+		} else {
+			layout.add_annotation(annotation(plot_index, &format!("{name_y} vs {name_x}")));
 		}
 	}
 
 	plot.set_layout(layout);
 
-	plot.write_image(
-		get_output(output, all_features),
-		image::FORMAT,
-		((image::WIDTH as f64) / 4.0 * size as f64) as usize,
-		((image::HEIGHT as f64) / 4.0 * size as f64) as usize,
-		image::SCALE,
-	);
+	let (width, height) = grid.image_size();
+	image::write(&plot, output, width, height)?;
 
 	Ok(())
 }
 
-fn get_output(output: PathBuf, all_features: bool) -> PathBuf {
-	if !all_features {
-		return output;
+/// subplot index with the x and y features of every cell of the grid, row by row
+fn cells(features: &[String]) -> impl Iterator<Item = (usize, &String, &String)> {
+	features.iter().enumerate().flat_map(move |(iy, name_y)| {
+		features
+			.iter()
+			.enumerate()
+			.map(move |(ix, name_x)| (iy * features.len() + ix + 1, name_x, name_y))
+	})
+}
+
+/// cells the group has values for, with no x on the diagonal
+fn panels<'a>(
+	group: &'a Group,
+	features: &'a [String],
+) -> impl Iterator<Item = (usize, Option<&'a [f64]>, &'a [f64])> {
+	cells(features).filter_map(move |(plot_index, name_x, name_y)| {
+		let y = group.feature(name_y)?;
+
+		if name_x == name_y {
+			Some((plot_index, None, y))
+		} else {
+			Some((plot_index, Some(group.feature(name_x)?), y))
+		}
+	})
+}
+
+/// histograms on the diagonal, scatter plots elsewhere
+fn print(groups: &[Group], features: &[String]) {
+	let width = terminal::panel_width(features.len());
+	let height = (width / 3).max(2);
+
+	let mut panels = Vec::with_capacity(features.len() * features.len());
+
+	for name_y in features {
+		for name_x in features {
+			if name_x == name_y {
+				let series = groups
+					.iter()
+					.filter_map(|group| Some((group.color, group.feature(name_y)?)))
+					.collect::<Vec<_>>();
+
+				panels.push(terminal::histogram(name_y, &series, width));
+			} else {
+				let series = groups
+					.iter()
+					.filter_map(|group| {
+						Some((group.color, group.feature(name_x)?, group.feature(name_y)?))
+					})
+					.collect::<Vec<_>>();
+
+				panels.push(terminal::scatter(
+					&format!("{name_y} vs {name_x}"),
+					&series,
+					width,
+					height,
+				));
+			}
+		}
 	}
 
-	let Some(filename) = output.file_stem() else {
-		return output;
+	println!("{}\n", terminal::legend(groups));
+	print!("{}", terminal::grid(&panels, width, features.len()));
+}
+
+/// `<stem>_complete.<extension>` next to `output`
+fn complete_output(output: &Path) -> PathBuf {
+	let Some(stem) = output.file_stem() else {
+		return output.to_path_buf();
 	};
 
-	PathBuf::from(format!(
-		"{}_complete.{}",
-		filename.to_string_lossy(),
-		output
-			.extension()
-			.unwrap_or_else(|| "".as_ref())
-			.to_string_lossy()
-	))
+	let mut name = stem.to_os_string();
+	name.push("_complete");
+	if let Some(extension) = output.extension() {
+		name.push(".");
+		name.push(extension);
+	}
+
+	output.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+	use polars::prelude::DataFrame;
+	use visualize::Color;
+
+	use super::*;
+
+	fn names(names: &[&str]) -> Vec<String> {
+		names.iter().map(|name| name.to_string()).collect()
+	}
+
+	#[test]
+	fn test_features() {
+		let all = names(&["a", "b", "c"]);
+
+		assert_eq!(features(&all, None).unwrap(), all);
+		assert_eq!(features(&all, Some(names(&["all"]))).unwrap(), all);
+		assert_eq!(
+			features(&all, Some(names(&["c", "a"]))).unwrap(),
+			names(&["a", "c"])
+		);
+		assert_eq!(features(&all, Some(names(&["?"]))).unwrap(), all);
+		assert!(features(&all, Some(names(&["d"]))).is_err());
+	}
+
+	#[test]
+	fn test_complete_output() {
+		assert_eq!(
+			complete_output(Path::new("pair_plot.png")),
+			Path::new("pair_plot_complete.png")
+		);
+		assert_eq!(
+			complete_output(Path::new("plots/pair_plot.html")),
+			Path::new("plots/pair_plot_complete.html")
+		);
+		assert_eq!(
+			complete_output(Path::new("plots/pair_plot")),
+			Path::new("plots/pair_plot_complete")
+		);
+	}
+
+	#[test]
+	fn test_panels() {
+		let features = names(&["a", "b", "c"]);
+		let group = Group {
+			label: "x".to_string(),
+			color: Color(0, 0, 0),
+			features: vec![("a".to_string(), vec![1.0]), ("c".to_string(), vec![3.0])],
+			df: DataFrame::empty(),
+		};
+
+		let panels = panels(&group, &features)
+			.map(|(plot_index, x, y)| (plot_index, x.map(|x| x[0]), y[0]))
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			panels,
+			[
+				(1, None, 1.0),
+				(3, Some(3.0), 1.0),
+				(7, Some(1.0), 3.0),
+				(9, None, 3.0),
+			]
+		);
+		assert_eq!(cells(&features).count(), 9);
+	}
 }
@@ -3,6 +3,11 @@ pub enum PlotType {
 	Histogram,
 	Scatter,
 	Pair,
+	Box,
+	Violin,
+	DecisionBoundary,
+	LearningCurve,
+	Confusion,
 }
 
 impl std::fmt::Display for PlotType {
@@ -11,6 +16,11 @@ impl std::fmt::Display for PlotType {
 			PlotType::Histogram => write!(f, "histogram"),
 			PlotType::Scatter => write!(f, "scatter"),
 			PlotType::Pair => write!(f, "pair"),
+			PlotType::Box => write!(f, "box"),
+			PlotType::Violin => write!(f, "violin"),
+			PlotType::DecisionBoundary => write!(f, "decision boundary"),
+			PlotType::LearningCurve => write!(f, "learning curve"),
+			PlotType::Confusion => write!(f, "confusion"),
 		}
 	}
 }
@@ -1,62 +1,171 @@
-use std::{error::Error, path::Path};
+use std::error::Error;
 
+use clap::{arg, value_parser, ArgMatches};
 use plotly::Plot;
 use polars::prelude::*;
 
 use visualize::{
-	annotation, args, feature, image, layout, populate, trace, Label, PlotType, LABEL_NAME,
+	annotation,
+	args::{self, Args},
+	correlation,
+	feature::{self, Group},
+	image,
+	layout::{self, Grid},
+	terminal, trace, Backend, Labels, PlotType,
 };
 
+const TERMINAL_COLUMNS: usize = 3;
+
 fn main() -> Result<(), Box<dyn Error>> {
-	let args = args::parse("scatter_plot.png");
+	let matches = args::command("scatter_plot.png")
+		.arg(
+			arg!(--x <feature>)
+				.help("feature on the horizontal axis")
+				.requires("y")
+				.required(false),
+		)
+		.arg(
+			arg!(--y <feature>)
+				.help("feature on the vertical axis")
+				.requires("x")
+				.required(false),
+		)
+		.arg(
+			arg!(--auto "rank the pairs of features by absolute correlation and plot the top ones (default without --x and --y)")
+				.conflicts_with("x"),
+		)
+		.arg(
+			arg!(--top <count>)
+				.help("number of pairs plotted by --auto")
+				.value_parser(value_parser!(usize))
+				.default_value("4")
+				.conflicts_with("x")
+				.required(false),
+		)
+		.get_matches();
+	let args = Args::from_matches(&matches);
+
+	let mut dataset = load::load(&args.csv)?;
+
+	args.expand_date(&mut dataset)?;
+
+	let labels = Labels::new(&dataset, &args.label, &args.palette)?;
+	let groups = feature::groups(&dataset, &labels)?;
+
+	let pairs = pairs(&dataset, &groups, &matches)?;
+
+	match args.backend {
+		Backend::Plotly => plot(&groups, &labels, &pairs, &args),
+		Backend::Terminal => print(&groups, &pairs, args.columns.unwrap_or(TERMINAL_COLUMNS)),
+	}
+}
 
-	let mut dataset = load::load(args.csv)?;
+/// the pair given by `--x` and `--y`, or the most correlated ones with their correlation
+fn pairs(
+	dataset: &DataFrame,
+	groups: &[Group],
+	matches: &ArgMatches,
+) -> Result<Vec<(String, String, Option<f64>)>, Box<dyn Error>> {
+	let names = feature::names(groups);
+
+	if let (Some(x), Some(y)) = (
+		matches.get_one::<String>("x"),
+		matches.get_one::<String>("y"),
+	) {
+		if let Some(unknown) = [x, y].into_iter().find(|name| !names.contains(name)) {
+			return Err(
+				format!("{unknown} is not a numeric feature ({})", names.join(", ")).into(),
+			);
+		}
 
-	populate::date(&mut dataset)?;
+		return Ok(vec![(x.clone(), y.clone(), None)]);
+	}
 
-	plot(dataset, args.output)
+	let top = *matches
+		.get_one::<usize>("top")
+		.expect("default ensures there is always a value");
+
+	Ok(correlation::ranked_pairs(dataset, &names)?
+		.into_iter()
+		.take(top)
+		.map(|(x, y, r)| (x, y, Some(r)))
+		.collect())
 }
 
-fn plot<P: AsRef<Path>>(dataset: DataFrame, output: P) -> Result<(), Box<dyn Error>> {
+fn plot(
+	groups: &[Group],
+	labels: &Labels,
+	pairs: &[(String, String, Option<f64>)],
+	args: &Args,
+) -> Result<(), Box<dyn Error>> {
 	let mut plot = Plot::new();
 
-	let mut layout = layout::build(PlotType::Scatter, 4);
+	let grid = Grid::new(pairs.len(), args.columns);
+	let mut layout = layout::build(PlotType::Scatter, grid, &labels.name);
 
-	for (i, df_label) in dataset
-		.partition_by([LABEL_NAME], true)?
-		.into_iter()
-		.enumerate()
-	{
-		let mut plot_index = 1;
+	for (i, (x, y, r)) in pairs.iter().enumerate() {
+		let plot_index = i + 1;
+
+		for group in groups {
+			let (xs, ys) = group.pair(x, y)?;
 
-		let (label, color) = Label::extract(&df_label)?;
+			plot.add_trace(trace::scatter(
+				xs,
+				ys,
+				&group.label,
+				group.color,
+				plot_index,
+			));
+		}
 
-		for series in df_label.get_columns() {
-			let Some(y) = feature::parse(series) else {
-				continue;
-			};
+		layout.add_annotation(annotation(plot_index, &title(x, y, *r)));
+	}
 
-			let t: Vec<f64> = y.iter().copied().rev().collect();
+	plot.set_layout(layout);
 
-			plot.add_trace(trace::scatter(t, y, &label, color, plot_index));
+	let (width, height) = grid.image_size();
+	image::write(&plot, &args.output, width, height)?;
 
-			if i == 0 {
-				layout.add_annotation(annotation(plot_index, series.name()));
-			}
+	Ok(())
+}
 
-			plot_index += 1;
-		}
+/// the correlation follows the names of the ranked pairs
+fn title(x: &str, y: &str, r: Option<f64>) -> String {
+	match r {
+		Some(r) => format!("{y} vs {x} ({r:+.3})"),
+		None => format!("{y} vs {x}"),
 	}
+}
 
-	plot.set_layout(layout);
+fn print(
+	groups: &[Group],
+	pairs: &[(String, String, Option<f64>)],
+	columns: usize,
+) -> Result<(), Box<dyn Error>> {
+	let columns = columns.min(pairs.len()).max(1);
+	let width = terminal::panel_width(columns);
+
+	let mut panels = Vec::with_capacity(pairs.len());
+	for (x, y, r) in pairs {
+		let values = groups
+			.iter()
+			.map(|group| Ok((group.color, group.pair(x, y)?)))
+			.collect::<PolarsResult<Vec<_>>>()?;
+		let series = values
+			.iter()
+			.map(|(color, (xs, ys))| (*color, xs.as_slice(), ys.as_slice()))
+			.collect::<Vec<_>>();
+
+		panels.push(terminal::scatter(
+			&title(x, y, *r),
+			&series,
+			width,
+			width / 3,
+		));
+	}
 
-	plot.write_image(
-		output,
-		image::FORMAT,
-		image::WIDTH,
-		image::HEIGHT,
-		image::SCALE,
-	);
+	println!("{}\n", terminal::legend(groups));
+	print!("{}", terminal::grid(&panels, width, columns));
 
 	Ok(())
 }
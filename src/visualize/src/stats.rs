@@ -0,0 +1,103 @@
+use analyze::quantile;
+
+/// first quartile, median and third quartile of sorted values
+pub fn quartiles(sorted: &[f64]) -> Option<(f64, f64, f64)> {
+	Some((
+		quantile(sorted, 0.25)?,
+		quantile(sorted, 0.5)?,
+		quantile(sorted, 0.75)?,
+	))
+}
+
+/// values further than 1.5 interquartile range from the quartiles
+pub fn outliers(sorted: &[f64]) -> Vec<f64> {
+	let Some((q1, _, q3)) = quartiles(sorted) else {
+		return Vec::new();
+	};
+
+	let fence = 1.5 * (q3 - q1);
+
+	sorted
+		.iter()
+		.copied()
+		.filter(|x| *x < q1 - fence || *x > q3 + fence)
+		.collect()
+}
+
+/// silverman's rule of thumb for the bandwidth of a gaussian kernel
+pub fn bandwidth(sorted: &[f64]) -> Option<f64> {
+	let n = sorted.len() as f64;
+	if sorted.len() < 2 {
+		return None;
+	}
+
+	let mean = sorted.iter().sum::<f64>() / n;
+	let std = (sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+	let (q1, _, q3) = quartiles(sorted)?;
+
+	let spread = match (q3 - q1) / 1.34 {
+		iqr if iqr > 0.0 => std.min(iqr),
+		_ => std,
+	};
+
+	(spread > 0.0).then(|| 0.9 * spread * n.powf(-0.2))
+}
+
+/// gaussian kernel density estimate of `values` at each of `points`
+pub fn kde(values: &[f64], bandwidth: f64, points: &[f64]) -> Vec<f64> {
+	let norm = values.len() as f64 * bandwidth * (2.0 * std::f64::consts::PI).sqrt();
+
+	points
+		.iter()
+		.map(|point| {
+			values
+				.iter()
+				.map(|x| (-0.5 * ((point - x) / bandwidth).powi(2)).exp())
+				.sum::<f64>()
+				/ norm
+		})
+		.collect()
+}
+
+/// `count` evenly spaced points from `min` to `max`
+pub fn linspace(min: f64, max: f64, count: usize) -> Vec<f64> {
+	match count {
+		0 => Vec::new(),
+		1 => vec![min],
+		_ => (0..count)
+			.map(|i| min + (max - min) * i as f64 / (count - 1) as f64)
+			.collect(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_quartiles() {
+		assert_eq!(quartiles(&[1.0, 2.0, 3.0, 4.0, 5.0]), Some((2.0, 3.0, 4.0)));
+		assert_eq!(quartiles(&[]), None);
+	}
+
+	#[test]
+	fn test_outliers() {
+		assert_eq!(
+			outliers(&[-20.0, 1.0, 2.0, 3.0, 4.0, 5.0, 30.0]),
+			[-20.0, 30.0]
+		);
+		assert!(outliers(&[1.0, 2.0, 3.0]).is_empty());
+	}
+
+	#[test]
+	fn test_kde() {
+		let values = [0.0, 1.0, 2.0, 3.0, 4.0];
+		let points = linspace(-20.0, 24.0, 4401);
+
+		let density = kde(&values, bandwidth(&values).unwrap(), &points);
+		let area = density.iter().sum::<f64>() * 0.01;
+
+		assert!((area - 1.0).abs() < 1e-3);
+		assert_eq!(bandwidth(&[1.0, 1.0]), None);
+	}
+}
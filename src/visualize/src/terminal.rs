@@ -0,0 +1,525 @@
+use analyze::histogram::{sparkline, Histogram};
+use float::Float;
+use terminal_size::{terminal_size, Width};
+
+use crate::{feature::Group, stats, Color};
+
+const DEFAULT_WIDTH: usize = 80;
+
+const HEATMAP_NAME_WIDTH: usize = 24;
+const POSITIVE: Color = Color(178, 24, 43);
+const NEGATIVE: Color = Color(33, 102, 172);
+const CONFUSION: Color = Color(8, 81, 156);
+
+const BRAILLE: u32 = 0x2800;
+/// bit of the dot at `[column][row]` of a braille character
+const DOTS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// width of the terminal, 80 when stdout is not a terminal
+pub fn width() -> usize {
+	terminal_size()
+		.map(|(Width(width), _)| width as usize)
+		.unwrap_or(DEFAULT_WIDTH)
+}
+
+pub fn paint(s: &str, color: Color) -> String {
+	let Color(r, g, b) = color;
+
+	format!("\u{1b}[38;2;{r};{g};{b}m{s}\u{1b}[0m")
+}
+
+/// a colored bullet and the name of each label
+pub fn legend(groups: &[Group]) -> String {
+	groups
+		.iter()
+		.map(|group| format!("{} {}", paint("●", group.color), group.label))
+		.collect::<Vec<_>>()
+		.join("  ")
+}
+
+/// braille characters, each one a grid of 2 by 4 dots
+pub struct Canvas {
+	width: usize,
+	height: usize,
+	cells: Vec<(u8, Option<Color>)>,
+}
+
+impl Canvas {
+	/// `width` and `height` in characters
+	pub fn new(width: usize, height: usize) -> Self {
+		let (width, height) = (width.max(1), height.max(1));
+
+		Self {
+			width,
+			height,
+			cells: vec![(0, None); width * height],
+		}
+	}
+
+	/// light the dot at `x` and `y` between 0 and 1, from the bottom left,
+	/// a character takes the color of its last dot
+	pub fn point(&mut self, x: f64, y: f64, color: Color) {
+		if !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y) {
+			return;
+		}
+
+		let column = (x * (self.width * 2 - 1) as f64).round() as usize;
+		let row = ((1.0 - y) * (self.height * 4 - 1) as f64).round() as usize;
+
+		let cell = &mut self.cells[row / 4 * self.width + column / 2];
+		cell.0 |= DOTS[column % 2][row % 4];
+		cell.1 = Some(color);
+	}
+
+	pub fn lines(&self) -> Vec<String> {
+		self.cells
+			.chunks(self.width)
+			.map(|line| {
+				line.iter()
+					.map(|(dots, color)| {
+						let c = char::from_u32(BRAILLE + *dots as u32)
+							.expect("braille patterns are valid characters")
+							.to_string();
+
+						match color {
+							Some(color) => paint(&c, *color),
+							None => c,
+						}
+					})
+					.collect()
+			})
+			.collect()
+	}
+}
+
+fn range<'v>(values: impl Iterator<Item = &'v f64>) -> Option<(f64, f64)> {
+	values.fold(None, |range, &x| match range {
+		None => Some((x, x)),
+		Some((min, max)) => Some((min.min(x), max.max(x))),
+	})
+}
+
+/// position of `x` in `range` between 0 and 1
+fn normalize(x: f64, (min, max): (f64, f64)) -> f64 {
+	if max <= min {
+		return 0.5;
+	}
+
+	(x - min) / (max - min)
+}
+
+/// title, then a sparkline of each label on the bins of all of them
+pub fn histogram(title: &str, series: &[(Color, &[f64])], width: usize) -> Vec<String> {
+	let mut lines = vec![title_line(title, width)];
+
+	let Some(range) = range(series.iter().flat_map(|(_, values)| values.iter())) else {
+		return lines;
+	};
+
+	let edges = Histogram::edges(range.0 as Float, range.1 as Float, width);
+
+	for (color, values) in series {
+		let histogram = Histogram::with_edges(values.iter().map(|&x| x as Float), edges.clone());
+
+		lines.push(paint(&sparkline(&histogram.counts), *color));
+	}
+
+	lines
+}
+
+/// title, then the points of every label on a braille canvas
+pub fn scatter(
+	title: &str,
+	series: &[(Color, &[f64], &[f64])],
+	width: usize,
+	height: usize,
+) -> Vec<String> {
+	let mut lines = vec![title_line(title, width)];
+
+	let x_range = range(series.iter().flat_map(|(_, x, _)| x.iter()));
+	let y_range = range(series.iter().flat_map(|(_, _, y)| y.iter()));
+	let (Some(x_range), Some(y_range)) = (x_range, y_range) else {
+		return lines;
+	};
+
+	let mut canvas = Canvas::new(width, height);
+	for (color, x, y) in series {
+		for (x, y) in x.iter().zip(y.iter()) {
+			canvas.point(normalize(*x, x_range), normalize(*y, y_range), *color);
+		}
+	}
+
+	lines.extend(canvas.lines());
+	lines
+}
+
+/// title, then a box plot of each label on the range of all of them,
+/// `├─██┃██─┤` from the lowest to the highest value that is not an outlier,
+/// outliers as `•`
+pub fn box_plot(title: &str, series: &[(Color, &[f64])], width: usize) -> Vec<String> {
+	let mut lines = vec![title_line(title, width)];
+
+	let Some(range) = range(series.iter().flat_map(|(_, values)| values.iter())) else {
+		return lines;
+	};
+
+	for (color, values) in series {
+		let mut sorted = values.to_vec();
+		sorted.sort_by(f64::total_cmp);
+
+		lines.push(paint(&box_line(&sorted, range, width), *color));
+	}
+
+	lines
+}
+
+fn box_line(sorted: &[f64], range: (f64, f64), width: usize) -> String {
+	let Some((q1, median, q3)) = stats::quartiles(sorted) else {
+		return " ".repeat(width);
+	};
+
+	let outliers = stats::outliers(sorted);
+	let inside = sorted
+		.iter()
+		.filter(|x| !outliers.contains(x))
+		.copied()
+		.collect::<Vec<_>>();
+	let (Some(low), Some(high)) = (inside.first(), inside.last()) else {
+		return " ".repeat(width);
+	};
+
+	let position = |x: f64| (normalize(x, range) * (width.max(1) - 1) as f64).round() as usize;
+	let (low, q1, median, q3, high) = (
+		position(*low),
+		position(q1),
+		position(median),
+		position(q3),
+		position(*high),
+	);
+
+	let mut line = (0..width)
+		.map(|i| match i {
+			_ if i < low || i > high => ' ',
+			_ if i == median => '┃',
+			_ if i == low => '├',
+			_ if i == high => '┤',
+			_ if (q1..=q3).contains(&i) => '█',
+			_ => '─',
+		})
+		.collect::<Vec<_>>();
+
+	for outlier in outliers {
+		if let Some(c) = line.get_mut(position(outlier)) {
+			*c = '•';
+		}
+	}
+
+	line.into_iter().collect()
+}
+
+/// white for 0, toward red for 1 and toward blue for -1
+pub fn diverging(r: f64) -> Color {
+	let color = if r >= 0.0 { POSITIVE } else { NEGATIVE };
+
+	color.fade(r.clamp(-1.0, 1.0).abs())
+}
+
+/// correlation matrix with a numbered row for each feature,
+/// each cell on the background of its diverging color
+pub fn heatmap(names: &[String], matrix: &[Vec<Option<f64>>]) -> String {
+	let cells = matrix
+		.iter()
+		.map(|row| {
+			row.iter()
+				.map(|r| match r {
+					Some(r) => {
+						let Color(red, green, blue) = diverging(*r);
+						format!("\u{1b}[30;48;2;{red};{green};{blue}m{r:+.2}\u{1b}[0m")
+					}
+					None => format!("{:>5}", "n/a"),
+				})
+				.collect()
+		})
+		.collect::<Vec<_>>();
+
+	numbered(names, &cells)
+}
+
+/// confusion matrix with a numbered row for each true label and a column for each prediction,
+/// each cell shaded by its share of the row
+pub fn confusion(names: &[String], counts: &[Vec<usize>]) -> String {
+	let cells = counts
+		.iter()
+		.map(|row| {
+			let total = row.iter().sum::<usize>().max(1);
+
+			row.iter()
+				.map(|&count| {
+					let Color(red, green, blue) = CONFUSION.fade(count as f64 / total as f64);
+					format!("\u{1b}[30;48;2;{red};{green};{blue}m{count:>5}\u{1b}[0m")
+				})
+				.collect()
+		})
+		.collect::<Vec<_>>();
+
+	numbered(names, &cells)
+}
+
+/// a row for each name, numbered like the columns, its cells 5 characters wide
+fn numbered(names: &[String], cells: &[Vec<String>]) -> String {
+	let name_width = names
+		.iter()
+		.map(|name| name.chars().count())
+		.max()
+		.unwrap_or_default()
+		.min(HEATMAP_NAME_WIDTH);
+	let index_width = names.len().to_string().len();
+
+	let mut out = " ".repeat(index_width + 1 + name_width);
+	for i in 1..=names.len() {
+		out += &format!(" {i:>5}");
+	}
+	out.push('\n');
+
+	for (i, (name, row)) in names.iter().zip(cells).enumerate() {
+		let name = name.chars().take(name_width).collect::<String>();
+		out += &format!("{:>index_width$} {name:<name_width$}", i + 1);
+
+		for cell in row {
+			out.push(' ');
+			out += cell;
+		}
+		out.push('\n');
+	}
+
+	out
+}
+
+/// title, then a bar for each label as long as its value between 0 and 1
+pub fn bars(title: &str, bars: &[(Color, &str, Option<f64>)], width: usize) -> Vec<String> {
+	let mut lines = vec![title_line(title, width)];
+
+	let name_width = bars
+		.iter()
+		.map(|(_, name, _)| name.chars().count())
+		.max()
+		.unwrap_or_default()
+		.min(width / 3);
+	// the name, a space, the bar, a space and the value
+	let bar_width = width.saturating_sub(name_width + 6);
+
+	for (color, name, value) in bars {
+		let name = name.chars().take(name_width).collect::<String>();
+
+		let line = match value {
+			Some(value) => {
+				let length = (value.clamp(0.0, 1.0) * bar_width as f64).round() as usize;
+				format!(
+					"{name:<name_width$} {}{} {value:.2}",
+					paint(&"█".repeat(length), *color),
+					" ".repeat(bar_width - length)
+				)
+			}
+			None => format!("{name:<name_width$} {} {:>4}", " ".repeat(bar_width), "n/a"),
+		};
+
+		lines.push(line);
+	}
+
+	lines
+}
+
+/// title, then a cell on the background of each region, rows from the top,
+/// with a `•` in the color of each point between 0 and 1 from the bottom left
+pub fn regions(title: &str, regions: &[Vec<Color>], points: &[(Color, f64, f64)]) -> Vec<String> {
+	let height = regions.len();
+	let width = regions.first().map(Vec::len).unwrap_or_default();
+
+	let mut lines = vec![title_line(title, width)];
+
+	if width == 0 {
+		return lines;
+	}
+
+	let mut marks = vec![vec![None; width]; height];
+	for &(color, x, y) in points {
+		if !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y) {
+			continue;
+		}
+
+		let column = (x * (width - 1) as f64).round() as usize;
+		let row = ((1.0 - y) * (height - 1) as f64).round() as usize;
+		marks[row][column] = Some(color);
+	}
+
+	for (region, marks) in regions.iter().zip(marks) {
+		let mut line = String::new();
+
+		for (&Color(r, g, b), mark) in region.iter().zip(marks) {
+			line += &format!("\u{1b}[48;2;{r};{g};{b}m");
+			line += &match mark {
+				Some(Color(r, g, b)) => format!("\u{1b}[38;2;{r};{g};{b}m•"),
+				None => String::from(" "),
+			};
+		}
+
+		lines.push(line + "\u{1b}[0m");
+	}
+
+	lines
+}
+
+fn title_line(title: &str, width: usize) -> String {
+	let title = title.chars().take(width).collect::<String>();
+
+	format!("\u{1b}[1m{title}\u{1b}[0m")
+}
+
+/// panels side by side, `columns` per row, each `width` characters wide
+pub fn grid(panels: &[Vec<String>], width: usize, columns: usize) -> String {
+	let mut out = String::new();
+
+	for row in panels.chunks(columns.max(1)) {
+		let height = row.iter().map(Vec::len).max().unwrap_or_default();
+
+		for i in 0..height {
+			let line = row
+				.iter()
+				.map(|panel| {
+					let line = panel.get(i).map(String::as_str).unwrap_or_default();
+					let padding = width.saturating_sub(visible_width(line));
+
+					format!("{line}{}", " ".repeat(padding))
+				})
+				.collect::<Vec<_>>()
+				.join(" ");
+
+			out += line.trim_end();
+			out.push('\n');
+		}
+
+		out.push('\n');
+	}
+
+	out
+}
+
+/// number of characters of `s` without its escape sequences
+fn visible_width(s: &str) -> usize {
+	let mut width = 0;
+	let mut escape = false;
+
+	for c in s.chars() {
+		match c {
+			'\u{1b}' => escape = true,
+			'm' if escape => escape = false,
+			_ if escape => {}
+			_ => width += 1,
+		}
+	}
+
+	width
+}
+
+/// width of a panel when `columns` of them share the terminal
+pub fn panel_width(columns: usize) -> usize {
+	let columns = columns.max(1);
+
+	(width().saturating_sub(columns - 1) / columns).max(4)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const RED: Color = Color(255, 0, 0);
+
+	#[test]
+	fn test_canvas() {
+		let mut canvas = Canvas::new(2, 1);
+
+		canvas.point(0.0, 1.0, RED);
+		canvas.point(1.0, 0.0, RED);
+		canvas.point(1.5, 0.0, RED);
+
+		assert_eq!(
+			canvas.lines(),
+			[format!("{}{}", paint("⠁", RED), paint("⢀", RED))]
+		);
+	}
+
+	#[test]
+	fn test_box_line() {
+		let sorted = [0.0, 2.0, 5.0, 8.0, 10.0];
+
+		assert_eq!(box_line(&sorted, (0.0, 10.0), 11), "├─███┃███─┤");
+		assert_eq!(
+			box_line(&[1.0, 2.0, 3.0, 4.0, 5.0, 10.0], (0.0, 10.0), 11),
+			" ├██┃┤    •"
+		);
+	}
+
+	#[test]
+	fn test_diverging() {
+		assert_eq!(diverging(0.0), Color(255, 255, 255));
+		assert_eq!(diverging(1.0), POSITIVE);
+		assert_eq!(diverging(-2.0), NEGATIVE);
+	}
+
+	#[test]
+	fn test_confusion() {
+		let names = [String::from("a"), String::from("b")];
+		let out = confusion(&names, &[vec![3, 1], vec![0, 0]]);
+		let lines = out.lines().collect::<Vec<_>>();
+
+		assert_eq!(lines[0], "        1     2");
+		assert_eq!(visible_width(lines[1]), "1 a".len() + 2 * 6);
+		assert!(lines[2].ends_with("\u{1b}[30;48;2;255;255;255m    0\u{1b}[0m"));
+	}
+
+	#[test]
+	fn test_bars() {
+		let lines = bars("t", &[(RED, "a", Some(0.5)), (RED, "bb", None)], 16);
+
+		assert_eq!(lines[1], format!("a  {}     0.50", paint("████", RED)));
+		assert_eq!(visible_width(&lines[1]), 16);
+		assert_eq!(lines[2], format!("bb {}  n/a", " ".repeat(8)));
+	}
+
+	#[test]
+	fn test_regions() {
+		let white = Color(255, 255, 255);
+		let lines = regions(
+			"t",
+			&[vec![white, RED], vec![RED, white]],
+			&[(RED, 0.0, 0.0)],
+		);
+
+		assert_eq!(lines.len(), 3);
+		assert_eq!(visible_width(&lines[1]), 2);
+		assert_eq!(
+			lines[2],
+			"\u{1b}[48;2;255;0;0m\u{1b}[38;2;255;0;0m•\u{1b}[48;2;255;255;255m \u{1b}[0m"
+		);
+	}
+
+	#[test]
+	fn test_grid() {
+		let panels = [
+			vec![String::from("a"), String::from("bb")],
+			vec![paint("c", RED)],
+			vec![String::from("d")],
+		];
+
+		assert_eq!(
+			grid(&panels, 3, 2),
+			format!("a   {}\nbb\n\nd\n\n", paint("c", RED))
+		);
+	}
+
+	#[test]
+	fn test_visible_width() {
+		assert_eq!(visible_width(&paint("⠁⢀", RED)), 2);
+		assert_eq!(visible_width("\u{1b}[1mtitle\u{1b}[0m"), 5);
+	}
+}
@@ -1,17 +1,19 @@
 use plotly::{
-	color::NamedColor,
-	common::{Marker, Mode},
-	Histogram, Scatter,
+	box_plot::BoxPoints,
+	common::{DashType, Fill, Line, Marker, Mode},
+	Bar, BoxPlot, Histogram, Scatter,
 };
 
+use crate::Color;
+
 pub fn histogram(
 	col: Vec<f64>,
 	label: &str,
-	color: NamedColor,
+	color: Color,
 	plot_index: usize,
 ) -> Box<Histogram<f64>> {
 	let ret = Histogram::new(col)
-		.marker(Marker::new().color(color))
+		.marker(Marker::new().color(color.rgb()))
 		.x_axis(format!("x{plot_index}"))
 		.y_axis(format!("y{plot_index}"));
 
@@ -26,12 +28,12 @@ pub fn scatter(
 	t: Vec<f64>,
 	y: Vec<f64>,
 	label: &str,
-	color: NamedColor,
+	color: Color,
 	plot_index: usize,
 ) -> Box<Scatter<f64, f64>> {
 	let ret = Scatter::new(t, y)
 		.mode(Mode::Markers)
-		.marker(Marker::new().color(color).size(3))
+		.marker(Marker::new().color(color.rgb()).size(3))
 		.x_axis(format!("x{plot_index}"))
 		.y_axis(format!("y{plot_index}"));
 
@@ -41,3 +43,112 @@ pub fn scatter(
 		ret.show_legend(false)
 	}
 }
+
+/// box of one label, its outliers drawn as points
+pub fn box_plot(
+	col: Vec<f64>,
+	label: &str,
+	color: Color,
+	plot_index: usize,
+) -> Box<BoxPlot<f64, f64>> {
+	BoxPlot::new(col)
+		.name(label)
+		.box_points(BoxPoints::Outliers)
+		.marker(Marker::new().color(color.rgb()).size(3))
+		.x_axis(format!("x{plot_index}"))
+		.y_axis(format!("y{plot_index}"))
+		.show_legend(plot_index == 1)
+}
+
+/// closed outline of a violin, filled with the color of its label
+pub fn violin(
+	x: Vec<f64>,
+	y: Vec<f64>,
+	label: &str,
+	color: Color,
+	plot_index: usize,
+) -> Box<Scatter<f64, f64>> {
+	let ret = Scatter::new(x, y)
+		.mode(Mode::Lines)
+		.fill(Fill::ToSelf)
+		.fill_color(color.rgb())
+		.line(Line::new().color(color.rgb()).width(1.0))
+		.opacity(0.6)
+		.x_axis(format!("x{plot_index}"))
+		.y_axis(format!("y{plot_index}"));
+
+	if plot_index == 1 {
+		ret.name(label)
+	} else {
+		ret.show_legend(false)
+	}
+}
+
+/// points of the outliers of a violin, never in the legend
+pub fn outliers(
+	x: Vec<f64>,
+	y: Vec<f64>,
+	color: Color,
+	plot_index: usize,
+) -> Box<Scatter<f64, f64>> {
+	Scatter::new(x, y)
+		.mode(Mode::Markers)
+		.marker(Marker::new().color(color.rgb()).size(4))
+		.x_axis(format!("x{plot_index}"))
+		.y_axis(format!("y{plot_index}"))
+		.show_legend(false)
+}
+
+/// curve in the color of a label, never in the legend
+pub fn line(x: Vec<f64>, y: Vec<f64>, color: Color, plot_index: usize) -> Box<Scatter<f64, f64>> {
+	Scatter::new(x, y)
+		.mode(Mode::Lines)
+		.line(Line::new().color(color.rgb()).width(2.0))
+		.x_axis(format!("x{plot_index}"))
+		.y_axis(format!("y{plot_index}"))
+		.show_legend(false)
+}
+
+/// named curve in the color of its label, in the legend of the first subplot only
+pub fn curve(
+	x: Vec<f64>,
+	y: Vec<f64>,
+	name: &str,
+	color: Color,
+	dashed: bool,
+	plot_index: usize,
+) -> Box<Scatter<f64, f64>> {
+	let dash = if dashed {
+		DashType::Dash
+	} else {
+		DashType::Solid
+	};
+
+	let ret = Scatter::new(x, y)
+		.mode(Mode::Lines)
+		.line(Line::new().color(color.rgb()).width(2.0).dash(dash))
+		.x_axis(format!("x{plot_index}"))
+		.y_axis(format!("y{plot_index}"));
+
+	if plot_index == 1 {
+		ret.name(name)
+	} else {
+		ret.show_legend(false)
+	}
+}
+
+/// single bar of a label, an undefined `value` keeps its place on the axis without a bar
+pub fn bar(
+	label: &str,
+	value: Option<f64>,
+	color: Color,
+	plot_index: usize,
+	legend: bool,
+) -> Box<Bar<String, Option<f64>>> {
+	Bar::new(vec![label.to_owned()], vec![value])
+		.name(label)
+		.marker(Marker::new().color(color.rgb()))
+		.x_axis(format!("x{plot_index}"))
+		.y_axis(format!("y{plot_index}"))
+		.show_legend(legend)
+}